@@ -0,0 +1,452 @@
+//! Transport-independent routing for the scoring API.
+//!
+//! Both the Vercel function in `api/handler.rs` and the standalone server in
+//! `src/bin/server.rs` turn their requests into a call to [`handle`] and
+//! write the returned [`ApiResponse`] back as JSON.
+
+use std::collections::BTreeMap;
+
+use http::{header::CONTENT_TYPE, HeaderMap, Method, StatusCode, Uri};
+use serde_json::json;
+
+use crate::hashcodes::{
+    error::ProblemError, problem::Dataset, response::ScoreResponse, ProblemKey,
+};
+
+#[path = "api_multipart.rs"]
+mod multipart;
+
+#[cfg(test)]
+#[path = "api_tests.rs"]
+mod tests;
+
+/// Status code and JSON payload of an API response.
+#[derive(Debug)]
+pub struct ApiResponse {
+    pub status: StatusCode,
+    pub payload: serde_json::Value,
+}
+
+impl ApiResponse {
+    pub fn new(status: StatusCode, payload: serde_json::Value) -> Self {
+        Self { status, payload }
+    }
+
+    /// Error envelope shared by every failing endpoint.
+    pub fn error(status: StatusCode, code: &str, message: impl Into<String>) -> Self {
+        Self::new(
+            status,
+            json!({
+                "status": "error",
+                "error": {
+                    "code": code,
+                    "message": message.into(),
+                }
+            }),
+        )
+    }
+
+    /// Serialized payload, ready to be sent with `Content-Type: application/json`.
+    pub fn body(&self) -> String {
+        self.payload.to_string()
+    }
+}
+
+/// Routes one request to the scorer:
+/// `POST /api/hashcodes/{year}/{round}/{input_file}`, or any path with
+/// `year`, `round` and `dataset` (or `input`) query parameters.
+/// `POST /api/hashcodes/{year}/{round}`, or the query parameters without a
+/// dataset, scores several datasets at once.
+///
+/// `GET /api/hashcodes` and `GET /api/hashcodes/{year}/{round}` (or the
+/// `year` and `round` query parameters) describe the registered problems and
+/// their datasets.
+pub fn handle(method: &Method, uri: &Uri, headers: &HeaderMap, body: &[u8]) -> ApiResponse {
+    let segments: Vec<&str> = uri
+        .path()
+        .trim_start_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    let relevant_segments: &[&str] = match segments.as_slice() {
+        ["api", rest @ ..] => rest,
+        other => other,
+    };
+
+    if method == Method::GET {
+        return match relevant_segments {
+            ["hashcodes"] => list_problems(),
+            ["hashcodes", year, round] => match ProblemKey::from_route(year, round) {
+                Some(key) => describe_round(key),
+                None => unknown_problem_response(year, round),
+            },
+            _ => match route_from_query(uri.query()) {
+                RouteMatch::Round { key } => describe_round(key),
+                RouteMatch::UnknownProblem { year, round } => {
+                    unknown_problem_response(&year, &round)
+                }
+                // Vercel rewrites `/api/hashcodes` to the bare handler.
+                RouteMatch::NotMatched if relevant_segments == ["handler"] => list_problems(),
+                RouteMatch::Matched { .. } | RouteMatch::NotMatched => ApiResponse::error(
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    "method-not-allowed",
+                    "GET is only supported for /api/hashcodes and /api/hashcodes/{year}/{round}",
+                ),
+            },
+        };
+    }
+    if method != Method::POST {
+        return ApiResponse::error(
+            StatusCode::METHOD_NOT_ALLOWED,
+            "method-not-allowed",
+            "Only GET and POST are supported",
+        );
+    }
+
+    let (problem_key, input_file_raw) = match relevant_segments {
+        ["hashcodes", year, round] => {
+            return match ProblemKey::from_route(year, round) {
+                Some(key) => score_round(key, headers, body),
+                None => unknown_problem_response(year, round),
+            };
+        }
+        ["hashcodes", year, round, input_file] => match ProblemKey::from_route(year, round) {
+            Some(key) => (key, (*input_file).to_owned()),
+            None => return unknown_problem_response(year, round),
+        },
+        _ => match route_from_query(uri.query()) {
+            RouteMatch::Matched { key, input_file } => (key, input_file),
+            RouteMatch::Round { key } => return score_round(key, headers, body),
+            RouteMatch::UnknownProblem { year, round } => {
+                return unknown_problem_response(&year, &round);
+            }
+            RouteMatch::NotMatched => return unknown_endpoint_response(),
+        },
+    };
+
+    let input_file = input_file_raw.trim();
+
+    let submission_text = match std::str::from_utf8(body) {
+        Ok(text) => text,
+        Err(_err) => {
+            return ApiResponse::error(
+                StatusCode::BAD_REQUEST,
+                "invalid-encoding",
+                "Submission body must be UTF-8 text",
+            )
+        }
+    };
+
+    match problem_key.score_submission(input_file, submission_text) {
+        Ok(score) => score_response(score),
+        Err(problem_error) => problem_error_response(problem_error),
+    }
+}
+
+/// Scores a round from a JSON object or a multipart form mapping dataset
+/// names to submissions.
+fn score_round(key: ProblemKey, headers: &HeaderMap, body: &[u8]) -> ApiResponse {
+    let submissions = match round_submissions(key, headers, body) {
+        Ok(submissions) => submissions,
+        Err(reply) => return reply,
+    };
+    let submissions: Vec<(&str, &str)> = submissions
+        .iter()
+        .map(|(name, text)| (name.as_str(), text.as_str()))
+        .collect();
+    match key.score_round(&submissions) {
+        Ok(round) => ApiResponse::new(
+            StatusCode::OK,
+            json!({
+                "status": "ok",
+                "result": round,
+            }),
+        ),
+        Err(problem_error) => problem_error_response(problem_error),
+    }
+}
+
+fn round_submissions(
+    key: ProblemKey,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<Vec<(String, String)>, ApiResponse> {
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    if let Some(boundary) = multipart::boundary(content_type) {
+        let parts = multipart::parse(body, boundary).map_err(problem_error_response)?;
+        let mut submissions = Vec::with_capacity(parts.len());
+        for part in parts {
+            // Browsers send an empty part for file inputs left blank.
+            if part.filename.as_deref() == Some("") && part.body.is_empty() {
+                continue;
+            }
+            // Prefer the field name, falling back to the uploaded file name
+            // for forms with a single multi-file input.
+            let name = match (part.name, part.filename) {
+                (Some(name), _) if key.datasets().iter().any(|d| d.matches(&name)) => name,
+                (_, Some(filename)) => filename,
+                (Some(name), None) => name,
+                (None, None) => {
+                    return Err(ApiResponse::error(
+                        StatusCode::BAD_REQUEST,
+                        "invalid-multipart",
+                        "Every part must name its dataset",
+                    ));
+                }
+            };
+            let text = String::from_utf8(part.body.to_vec()).map_err(|_err| {
+                ApiResponse::error(
+                    StatusCode::BAD_REQUEST,
+                    "invalid-encoding",
+                    format!("Submission for '{name}' must be UTF-8 text"),
+                )
+            })?;
+            submissions.push((name, text));
+        }
+        return Ok(submissions);
+    }
+
+    let media_type = content_type.split(';').next().unwrap_or_default().trim();
+    if !media_type.eq_ignore_ascii_case("application/json") {
+        return Err(ApiResponse::error(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "unsupported-media-type",
+            "Send submissions as multipart/form-data or as a JSON object of dataset names \
+             to submission texts",
+        ));
+    }
+    serde_json::from_slice::<BTreeMap<String, String>>(body)
+        .map(|submissions| submissions.into_iter().collect())
+        .map_err(|error| {
+            ApiResponse::error(
+                StatusCode::BAD_REQUEST,
+                "invalid-json",
+                format!("Expected a JSON object of dataset names to submissions: {error}"),
+            )
+        })
+}
+
+pub fn score_response(score: ScoreResponse) -> ApiResponse {
+    ApiResponse::new(
+        StatusCode::OK,
+        json!({
+            "status": "ok",
+            "result": score,
+        }),
+    )
+}
+
+pub fn problem_error_response(error: ProblemError) -> ApiResponse {
+    let status = match error.code {
+        "unknown-input-file" => StatusCode::NOT_FOUND,
+        _ => StatusCode::BAD_REQUEST,
+    };
+    ApiResponse::new(
+        status,
+        json!({
+            "status": "error",
+            "error": error,
+        }),
+    )
+}
+
+/// Every registered round with its datasets, as
+/// `{"status": "ok", "problems": [...]}`.
+pub fn list_problems() -> ApiResponse {
+    let problems: Vec<serde_json::Value> = ProblemKey::all()
+        .map(|key| describe_problem(key, describe_dataset))
+        .collect();
+    ApiResponse::new(
+        StatusCode::OK,
+        json!({
+            "status": "ok",
+            "problems": problems,
+        }),
+    )
+}
+
+/// One round with the size and header line of each bundled input, as
+/// `{"status": "ok", "problem": {...}}`.
+pub fn describe_round(key: ProblemKey) -> ApiResponse {
+    ApiResponse::new(
+        StatusCode::OK,
+        json!({
+            "status": "ok",
+            "problem": describe_problem(key, describe_dataset_input),
+        }),
+    )
+}
+
+fn describe_problem(
+    key: ProblemKey,
+    describe: fn(&Dataset) -> serde_json::Value,
+) -> serde_json::Value {
+    let datasets: Vec<serde_json::Value> = key.datasets().iter().map(describe).collect();
+    json!({
+        "id": key.id(),
+        "year": key.year(),
+        "round": key.round(),
+        "aliases": key.aliases(),
+        "solver": key.has_solver(),
+        "datasets": datasets,
+    })
+}
+
+fn describe_dataset(dataset: &Dataset) -> serde_json::Value {
+    json!({
+        "name": dataset.name,
+        "file": dataset.file,
+        "aliases": dataset.aliases,
+    })
+}
+
+/// Adds input statistics to [`describe_dataset`]. Header values are the
+/// whitespace-separated fields of the first line, as numbers where possible.
+fn describe_dataset_input(dataset: &Dataset) -> serde_json::Value {
+    let header: Vec<serde_json::Value> = dataset
+        .contents
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|field| match field.parse::<i64>() {
+            Ok(number) => json!(number),
+            Err(_err) => json!(field),
+        })
+        .collect();
+    let mut description = describe_dataset(dataset);
+    description["size_bytes"] = json!(dataset.contents.len());
+    description["lines"] = json!(dataset.contents.lines().count());
+    description["header"] = json!(header);
+    description
+}
+
+pub fn unknown_problem_response(year: &str, round: &str) -> ApiResponse {
+    ApiResponse::error(
+        StatusCode::NOT_FOUND,
+        "unknown-problem",
+        format!("No Hash Code implementation registered for year={year} round={round}"),
+    )
+}
+
+fn unknown_endpoint_response() -> ApiResponse {
+    ApiResponse::error(
+        StatusCode::NOT_FOUND,
+        "unknown-endpoint",
+        "Expected /api/hashcodes/{year}/{round} or /api/hashcodes/{year}/{round}/{input_file}",
+    )
+}
+
+enum RouteMatch {
+    Matched {
+        key: ProblemKey,
+        input_file: String,
+    },
+    /// `year` and `round` without a dataset: the whole round.
+    Round {
+        key: ProblemKey,
+    },
+    UnknownProblem {
+        year: String,
+        round: String,
+    },
+    NotMatched,
+}
+
+fn route_from_query(query: Option<&str>) -> RouteMatch {
+    let query = match query {
+        Some(value) if !value.is_empty() => value,
+        _ => return RouteMatch::NotMatched,
+    };
+
+    let mut year: Option<String> = None;
+    let mut round: Option<String> = None;
+    let mut dataset: Option<String> = None;
+
+    for pair in query.split('&') {
+        if pair.is_empty() {
+            continue;
+        }
+
+        let mut parts = pair.splitn(2, '=');
+        let name = parts.next().unwrap_or("");
+        let raw_value = parts.next().unwrap_or("");
+        let decoded =
+            decode_uri_component(raw_value).unwrap_or_else(|| raw_value.replace('+', " "));
+        let value = decoded.trim().to_owned();
+
+        match name {
+            "year" if year.is_none() => {
+                year = Some(value);
+            }
+            "round" if round.is_none() => {
+                round = Some(value);
+            }
+            "dataset" | "input" if dataset.is_none() => {
+                dataset = Some(value);
+            }
+            _ => {}
+        }
+    }
+
+    let (year, round) = match (year, round) {
+        (Some(year), Some(round)) => (year, round),
+        _ => return RouteMatch::NotMatched,
+    };
+
+    match (
+        ProblemKey::from_route(year.as_str(), round.as_str()),
+        dataset,
+    ) {
+        (Some(key), Some(dataset)) => RouteMatch::Matched {
+            key,
+            input_file: dataset,
+        },
+        (Some(key), None) => RouteMatch::Round { key },
+        (None, _) => RouteMatch::UnknownProblem { year, round },
+    }
+}
+
+fn decode_uri_component(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                if index + 2 >= bytes.len() {
+                    return None;
+                }
+                let high = from_hex_digit(bytes[index + 1])?;
+                let low = from_hex_digit(bytes[index + 2])?;
+                result.push((high << 4) | low);
+                index += 3;
+            }
+            b'+' => {
+                result.push(b' ');
+                index += 1;
+            }
+            byte => {
+                result.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8(result).ok()
+}
+
+fn from_hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
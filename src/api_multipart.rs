@@ -0,0 +1,102 @@
+//! Minimal `multipart/form-data` reader for round uploads. Parts are kept as
+//! borrowed byte slices; only the headers the API needs are parsed.
+
+use crate::hashcodes::error::ProblemError;
+
+/// One form field or uploaded file.
+#[derive(Debug)]
+pub struct Part<'a> {
+    pub name: Option<String>,
+    pub filename: Option<String>,
+    pub body: &'a [u8],
+}
+
+/// Extracts the boundary from a `multipart/form-data` content type.
+pub fn boundary(content_type: &str) -> Option<&str> {
+    let mut params = content_type.split(';');
+    let media_type = params.next()?.trim();
+    if !media_type.eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+    params
+        .filter_map(|param| param.trim().split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| value.trim().trim_matches('"'))
+        .filter(|value| !value.is_empty())
+}
+
+pub fn parse<'a>(body: &'a [u8], boundary: &str) -> Result<Vec<Part<'a>>, ProblemError> {
+    let delimiter = format!("--{boundary}");
+    let delimiter = delimiter.as_bytes();
+    let malformed = |message: &str| ProblemError::new("invalid-multipart", message.to_owned());
+
+    let mut position = find(body, delimiter, 0)
+        .ok_or_else(|| malformed("Body does not contain the multipart boundary"))?
+        + delimiter.len();
+    let mut parts = Vec::new();
+    loop {
+        let rest = &body[position..];
+        if rest.starts_with(b"--") {
+            return Ok(parts);
+        }
+        let headers_start = position
+            + if rest.starts_with(b"\r\n") {
+                2
+            } else if rest.starts_with(b"\n") {
+                1
+            } else {
+                return Err(malformed("Boundary is not followed by a line break"));
+            };
+        // Headers end at the first blank line, whichever line break it uses:
+        // the file body may use CRLF even when the headers do not.
+        let crlf = find(body, b"\r\n\r\n", headers_start).map(|end| (end, end + 4));
+        let lf = find(body, b"\n\n", headers_start).map(|end| (end, end + 2));
+        let (headers_end, body_start) = match (crlf, lf) {
+            (Some(crlf), Some(lf)) => crlf.min(lf),
+            (crlf, lf) => crlf
+                .or(lf)
+                .ok_or_else(|| malformed("Part headers are not terminated by a blank line"))?,
+        };
+        let body_end = find(body, delimiter, body_start)
+            .ok_or_else(|| malformed("Body is missing the closing boundary"))?;
+        position = body_end + delimiter.len();
+
+        let content = &body[body_start..body_end];
+        let content = content.strip_suffix(b"\n").unwrap_or(content);
+        let content = content.strip_suffix(b"\r").unwrap_or(content);
+        let headers = String::from_utf8_lossy(&body[headers_start..headers_end]);
+        let mut part = Part {
+            name: None,
+            filename: None,
+            body: content,
+        };
+        for line in headers.lines() {
+            let Some((header, value)) = line.split_once(':') else {
+                continue;
+            };
+            if !header.trim().eq_ignore_ascii_case("content-disposition") {
+                continue;
+            }
+            for param in value.split(';').skip(1) {
+                let Some((name, value)) = param.trim().split_once('=') else {
+                    continue;
+                };
+                let value = value.trim().trim_matches('"').to_owned();
+                match name.trim() {
+                    "name" => part.name = Some(value),
+                    "filename" => part.filename = Some(value),
+                    _ => {}
+                }
+            }
+        }
+        parts.push(part);
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|offset| from + offset)
+}
@@ -0,0 +1,228 @@
+use http::{header::CONTENT_TYPE, HeaderMap, HeaderValue, Method, StatusCode, Uri};
+
+use super::handle;
+
+const TRAFFIC_SUBMISSION: &str =
+    "3\n1\n2\nrue-d-athenes 2\nrue-d-amsterdam 1\n0\n1\nrue-de-londres 2\n2\n1\nrue-de-moscou 1\n";
+
+fn get(uri: &str) -> super::ApiResponse {
+    let uri: Uri = uri.parse().expect("uri");
+    handle(&Method::GET, &uri, &HeaderMap::new(), b"")
+}
+
+fn post(uri: &str, body: &str) -> super::ApiResponse {
+    let uri: Uri = uri.parse().expect("uri");
+    handle(&Method::POST, &uri, &HeaderMap::new(), body.as_bytes())
+}
+
+fn post_with_type(uri: &str, content_type: &str, body: &str) -> super::ApiResponse {
+    let uri: Uri = uri.parse().expect("uri");
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_str(content_type).expect("header"),
+    );
+    handle(&Method::POST, &uri, &headers, body.as_bytes())
+}
+
+#[test]
+fn scores_submissions_by_path() {
+    let reply = post(
+        "/api/hashcodes/2021/qualification/a_example",
+        TRAFFIC_SUBMISSION,
+    );
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(reply.payload["status"], "ok");
+    assert_eq!(reply.payload["result"]["score"], 1002);
+}
+
+#[test]
+fn falls_back_to_query_parameters() {
+    let reply = post(
+        "/api?year=2021&round=qualification_round&dataset=a_example.in",
+        TRAFFIC_SUBMISSION,
+    );
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(
+        reply.payload["result"]["problem"],
+        "hashcode_2021_qualification_round"
+    );
+}
+
+#[test]
+fn reports_routing_errors() {
+    let reply = post("/api/hashcodes/2013/final/a_example", "");
+    assert_eq!(reply.status, StatusCode::NOT_FOUND);
+    assert_eq!(reply.payload["error"]["code"], "unknown-problem");
+
+    let reply = post("/api/hashcodes/2021/qualification/z_missing", "");
+    assert_eq!(reply.status, StatusCode::NOT_FOUND);
+    assert_eq!(reply.payload["error"]["code"], "unknown-input-file");
+
+    let reply = post("/api/scores", "");
+    assert_eq!(reply.payload["error"]["code"], "unknown-endpoint");
+
+    let reply = post("/api/hashcodes/2021/qualification/a_example", "3\n");
+    assert_eq!(reply.status, StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn rejects_other_methods() {
+    let uri: Uri = "/api/hashcodes/2021/qualification/a_example"
+        .parse()
+        .expect("uri");
+    let reply = handle(&Method::PUT, &uri, &HeaderMap::new(), b"");
+    assert_eq!(reply.status, StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(reply.payload["error"]["code"], "method-not-allowed");
+}
+
+#[test]
+fn lists_registered_problems() {
+    let reply = super::list_problems();
+    assert_eq!(reply.status, StatusCode::OK);
+    let problems = reply.payload["problems"].as_array().expect("problems");
+    assert!(problems.len() >= 20);
+    let pizza = problems
+        .iter()
+        .find(|problem| problem["id"] == "hashcode_2017_practice_round")
+        .expect("2017 practice listed");
+    assert_eq!(pizza["solver"], true);
+    assert_eq!(pizza["datasets"][0]["name"], "a_example");
+}
+
+#[test]
+fn scores_a_round_from_a_json_map() {
+    let body = serde_json::json!({ "a_example": TRAFFIC_SUBMISSION, "b_ocean": "1\n" });
+    let reply = post_with_type(
+        "/api/hashcodes/2021/qualification",
+        "application/json",
+        &body.to_string(),
+    );
+    assert_eq!(reply.status, StatusCode::OK);
+    let result = &reply.payload["result"];
+    assert_eq!(result["total_score"], 1002);
+    assert_eq!(result["datasets"][0]["result"]["score"], 1002);
+    assert!(result["datasets"][1]["error"]["code"].is_string());
+    assert_eq!(result["datasets"][2]["submitted"], false);
+    assert_eq!(result["datasets"][2]["score"], 0);
+}
+
+#[test]
+fn scores_a_round_from_query_parameters() {
+    let body = serde_json::json!({ "a_example": TRAFFIC_SUBMISSION });
+    let reply = post_with_type(
+        "/api/handler?year=2021&round=qualification",
+        "application/json",
+        &body.to_string(),
+    );
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(reply.payload["result"]["total_score"], 1002);
+    assert_eq!(reply.payload["result"]["datasets"][1]["submitted"], false);
+}
+
+#[test]
+fn scores_a_round_from_a_multipart_form() {
+    let body = format!(
+        "--XyZ\r\nContent-Disposition: form-data; name=\"a_example\"\r\n\r\n\
+         {TRAFFIC_SUBMISSION}\r\n\
+         --XyZ\r\nContent-Disposition: form-data; name=\"files\"; filename=\"\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n\r\n--XyZ--\r\n"
+    );
+    let reply = post_with_type(
+        "/api/hashcodes/2021/qualification",
+        "multipart/form-data; boundary=XyZ",
+        &body,
+    );
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(reply.payload["result"]["total_score"], 1002);
+
+    // Output file names from a multi-file input match their dataset.
+    let body = format!(
+        "--XyZ\r\nContent-Disposition: form-data; name=\"files\"; filename=\"a_example.out\"\r\n\
+         \r\n{TRAFFIC_SUBMISSION}\r\n--XyZ--\r\n"
+    );
+    let reply = post_with_type(
+        "/api/hashcodes/2021/qualification",
+        "multipart/form-data; boundary=XyZ",
+        &body,
+    );
+    assert_eq!(reply.payload["result"]["total_score"], 1002);
+
+    // LF part headers do not swallow a CRLF file body up to its first blank line.
+    let body = format!(
+        "--XyZ\nContent-Disposition: form-data; name=\"files\"; filename=\"a_example.out\"\n\
+         \n{}\r\n\r\n--XyZ--\n",
+        TRAFFIC_SUBMISSION.replace('\n', "\r\n")
+    );
+    let reply = post_with_type(
+        "/api/hashcodes/2021/qualification",
+        "multipart/form-data; boundary=XyZ",
+        &body,
+    );
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(reply.payload["result"]["total_score"], 1002);
+
+    let reply = post_with_type("/api/hashcodes/2021/qualification", "text/plain", "");
+    assert_eq!(reply.status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    let reply = post_with_type(
+        "/api/hashcodes/2021/qualification",
+        "application/json",
+        r#"{"z_missing": ""}"#,
+    );
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(
+        reply.payload["result"]["datasets"][6]["error"]["code"],
+        "unknown-input-file"
+    );
+}
+
+#[test]
+fn describes_rounds_on_get() {
+    let reply = get("/api/hashcodes");
+    assert_eq!(reply.status, StatusCode::OK);
+    assert!(
+        reply.payload["problems"]
+            .as_array()
+            .expect("problems")
+            .len()
+            >= 20
+    );
+
+    let reply = get("/api/hashcodes/2017/practice_round");
+    assert_eq!(reply.status, StatusCode::OK);
+    let problem = &reply.payload["problem"];
+    assert_eq!(problem["id"], "hashcode_2017_practice_round");
+    let example = &problem["datasets"][0];
+    assert_eq!(example["name"], "a_example");
+    assert_eq!(example["header"], serde_json::json!([3, 5, 1, 6]));
+    assert_eq!(example["lines"], 4);
+
+    assert_eq!(
+        get("/api/hashcodes/2013/final").status,
+        StatusCode::NOT_FOUND
+    );
+    let reply = get("/api/hashcodes/2017/practice/a_example");
+    assert_eq!(reply.status, StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[test]
+fn describes_rounds_on_get_through_the_vercel_rewrites() {
+    let reply = get("/api/handler");
+    assert_eq!(reply.status, StatusCode::OK);
+    assert!(reply.payload["problems"].is_array());
+
+    let reply = get("/api/handler?year=2017&round=practice");
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(
+        reply.payload["problem"]["id"],
+        "hashcode_2017_practice_round"
+    );
+    assert_eq!(
+        get("/api/handler?year=2013&round=final").status,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        get("/api/handler?year=2017&round=practice&dataset=a_example").status,
+        StatusCode::METHOD_NOT_ALLOWED
+    );
+}
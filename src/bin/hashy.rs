@@ -0,0 +1,454 @@
+//! Command-line front end for the scorers, for checking submissions without
+//! running the HTTP API.
+//!
+//! `--json` prints the same payloads as `api/handler.rs`.
+
+use std::fmt::Write as _;
+use std::io::{Read, Write};
+use std::process::ExitCode;
+
+use http::StatusCode;
+
+use hashy::api::{self, ApiResponse};
+use hashy::hashcodes::error::ProblemError;
+use hashy::hashcodes::response::ScoreResponse;
+use hashy::hashcodes::ProblemKey;
+
+const USAGE: &str = "\
+Usage: hashy [--json] <command> [arguments]
+
+Commands:
+  score <year> <round> <dataset> <file>   Score a submission ('-' reads stdin)
+  validate <year> <round> <file>          Check that a submission parses
+  solve <year> <round> <dataset>          Run the round's bundled solver
+  list                                    List problems and their datasets
+
+Options:
+  --json            Print the JSON payload the HTTP API would return
+  --output FILE     Write the solver's submission to FILE instead of stdout
+
+Exit status:
+  0  success
+  1  the submission was rejected
+  2  invalid arguments, unknown problem, dataset or solver
+  3  internal error, e.g. an unreadable file or a failing bundled solver";
+
+const EXIT_REJECTED: u8 = 1;
+const EXIT_USAGE: u8 = 2;
+const EXIT_INTERNAL: u8 = 3;
+
+#[derive(Debug, Default)]
+struct Cli {
+    json: bool,
+    output: Option<String>,
+    command: Vec<String>,
+}
+
+impl Cli {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut cli = Cli::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--json" => cli.json = true,
+                "-o" | "--output" => {
+                    let file = args
+                        .next()
+                        .ok_or_else(|| format!("Missing value for {arg}"))?;
+                    cli.output = Some(file);
+                }
+                "-h" | "--help" => return Err(String::new()),
+                flag if flag.starts_with("--") => {
+                    return Err(format!("Unknown option '{flag}'"));
+                }
+                _ => cli.command.push(arg),
+            }
+        }
+        Ok(cli)
+    }
+}
+
+/// Why a command failed, with the exit status and the payload to report.
+struct Failure {
+    exit: u8,
+    reply: ApiResponse,
+}
+
+impl Failure {
+    fn usage(message: impl Into<String>) -> Self {
+        Self {
+            exit: EXIT_USAGE,
+            reply: ApiResponse::error(StatusCode::BAD_REQUEST, "invalid-arguments", message),
+        }
+    }
+
+    fn internal(code: &str, message: impl Into<String>) -> Self {
+        Self {
+            exit: EXIT_INTERNAL,
+            reply: ApiResponse::error(StatusCode::INTERNAL_SERVER_ERROR, code, message),
+        }
+    }
+
+    /// Lookup failures are usage errors; anything else exits with `exit`.
+    fn problem(error: ProblemError, exit: u8) -> Self {
+        let exit = match error.code {
+            "unknown-input-file" | "no-solver" => EXIT_USAGE,
+            _ => exit,
+        };
+        Self {
+            exit,
+            reply: api::problem_error_response(error),
+        }
+    }
+
+    fn report(&self, json: bool) {
+        if json {
+            // Nothing left to report if stdout is gone.
+            let _ = print(&format!("{}\n", self.reply.body()));
+            return;
+        }
+        let error = &self.reply.payload["error"];
+        let code = error["code"].as_str().unwrap_or_default();
+        let message = error["message"].as_str().unwrap_or_default();
+        eprintln!("error[{code}]: {message}");
+        if let Some(details) = error.get("details") {
+            eprintln!("{details:#}");
+        }
+        if code == "invalid-arguments" {
+            eprintln!("\n{USAGE}");
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = match Cli::from_args(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(message) if message.is_empty() => {
+            let _ = print(&format!("{USAGE}\n"));
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => {
+            failure.report(cli.json);
+            ExitCode::from(failure.exit)
+        }
+    }
+}
+
+fn run(cli: &Cli) -> Result<(), Failure> {
+    let (command, args) = match cli.command.split_first() {
+        Some((command, args)) => (command.as_str(), args),
+        None => return Err(Failure::usage("Missing command")),
+    };
+    if cli.output.is_some() && command != "solve" {
+        return Err(Failure::usage("--output is only supported by 'solve'"));
+    }
+
+    match command {
+        "score" => {
+            let [year, round, dataset, file] = positional(command, args)?;
+            let key = lookup(year, round)?;
+            let text = read_submission(file)?;
+            let score = key
+                .score_submission(dataset, &text)
+                .map_err(|error| Failure::problem(error, EXIT_REJECTED))?;
+            if cli.json {
+                print(&format!("{}\n", api::score_response(score).body()))
+            } else {
+                print(&format_score(&score))
+            }
+        }
+        "validate" => {
+            let [year, round, file] = positional(command, args)?;
+            let key = lookup(year, round)?;
+            let text = read_submission(file)?;
+            key.validate_submission(&text)
+                .map_err(|error| Failure::problem(error, EXIT_REJECTED))?;
+            if cli.json {
+                let payload = serde_json::json!({
+                    "status": "ok",
+                    "result": { "problem": key.id(), "valid": true },
+                });
+                print(&format!("{payload}\n"))
+            } else {
+                print(&format!("{file}: valid {id} submission\n", id = key.id()))
+            }
+        }
+        "solve" => {
+            let [year, round, dataset] = positional(command, args)?;
+            let key = lookup(year, round)?;
+            solve(cli, key, dataset)
+        }
+        "list" => {
+            let [] = positional(command, args)?;
+            if cli.json {
+                print(&format!("{}\n", api::list_problems().body()))
+            } else {
+                print(&format_problems())
+            }
+        }
+        other => Err(Failure::usage(format!("Unknown command '{other}'"))),
+    }
+}
+
+/// Runs the bundled solver and scores its output, so a solver that produces
+/// an invalid submission is reported as an internal error.
+fn solve(cli: &Cli, key: ProblemKey, dataset: &str) -> Result<(), Failure> {
+    let text = key
+        .solve(dataset)
+        .map_err(|error| Failure::problem(error, EXIT_INTERNAL))?;
+    let score = key
+        .score_submission(dataset, &text)
+        .map_err(|error| Failure::problem(error, EXIT_INTERNAL))?;
+
+    if let Some(path) = &cli.output {
+        std::fs::write(path, &text).map_err(|error| {
+            Failure::internal("unwritable-file", format!("Cannot write '{path}': {error}"))
+        })?;
+    }
+    if cli.json {
+        let mut reply = api::score_response(score);
+        reply.payload["submission"] = serde_json::Value::String(text);
+        print(&format!("{}\n", reply.body()))
+    } else if cli.output.is_some() {
+        print(&format_score(&score))
+    } else {
+        // Keep stdout a valid submission file.
+        print(&text)?;
+        eprintln!("score: {score}", score = score.score);
+        Ok(())
+    }
+}
+
+/// Writes `text` to stdout. A closed pipe, as in `hashy list | head`, ends
+/// the output quietly instead of panicking like `println!`.
+fn print(text: &str) -> Result<(), Failure> {
+    let mut stdout = std::io::stdout().lock();
+    match stdout
+        .write_all(text.as_bytes())
+        .and_then(|()| stdout.flush())
+    {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::BrokenPipe => Ok(()),
+        Err(error) => Err(Failure::internal(
+            "unwritable-file",
+            format!("Cannot write stdout: {error}"),
+        )),
+    }
+}
+
+fn positional<'a, const N: usize>(
+    command: &str,
+    args: &'a [String],
+) -> Result<[&'a str; N], Failure> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    args.try_into()
+        .map_err(|_args| Failure::usage(format!("'{command}' expects {N} arguments")))
+}
+
+fn lookup(year: &str, round: &str) -> Result<ProblemKey, Failure> {
+    ProblemKey::from_route(year, round).ok_or_else(|| Failure {
+        exit: EXIT_USAGE,
+        reply: api::unknown_problem_response(year, round),
+    })
+}
+
+/// Reads a submission from `path`, or from stdin when `path` is `-`.
+fn read_submission(path: &str) -> Result<String, Failure> {
+    let mut bytes = Vec::new();
+    let read = if path == "-" {
+        std::io::stdin().read_to_end(&mut bytes).map(drop)
+    } else {
+        std::fs::read(path).map(|contents| bytes = contents)
+    };
+    read.map_err(|error| {
+        Failure::internal("unreadable-file", format!("Cannot read '{path}': {error}"))
+    })?;
+    String::from_utf8(bytes).map_err(|_err| Failure {
+        exit: EXIT_REJECTED,
+        reply: ApiResponse::error(
+            StatusCode::BAD_REQUEST,
+            "invalid-encoding",
+            "Submission must be UTF-8 text",
+        ),
+    })
+}
+
+fn format_score(score: &ScoreResponse) -> String {
+    let mut out = format!(
+        "{problem} {input}: {total}\n",
+        problem = score.problem,
+        input = score.input_file,
+        total = score.score
+    );
+    let Some(serde_json::Value::Object(details)) = &score.details else {
+        return out;
+    };
+    for (field, value) in details {
+        let _ = match value {
+            serde_json::Value::Array(items) => writeln!(out, "  {field}: {} entries", items.len()),
+            serde_json::Value::String(text) => writeln!(out, "  {field}: {text}"),
+            other => writeln!(out, "  {field}: {other}"),
+        };
+    }
+    out
+}
+
+fn format_problems() -> String {
+    let mut out = String::new();
+    for key in ProblemKey::all() {
+        let solver = if key.has_solver() { " [solver]" } else { "" };
+        let _ = writeln!(
+            out,
+            "{year} {round}{solver}  {id}",
+            year = key.year(),
+            round = key.round(),
+            id = key.id()
+        );
+        if !key.aliases().is_empty() {
+            let _ = writeln!(out, "    round aliases: {}", key.aliases().join(", "));
+        }
+        for dataset in key.datasets() {
+            let _ = writeln!(
+                out,
+                "    {name}  ({file})",
+                name = dataset.name,
+                file = dataset.file
+            );
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use hashy::hashcodes::error::ProblemError;
+    use hashy::hashcodes::ProblemKey;
+
+    use super::{positional, run, Cli, Failure, EXIT_INTERNAL, EXIT_REJECTED, EXIT_USAGE};
+
+    fn parse(args: &[&str]) -> Result<Cli, String> {
+        Cli::from_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn exit_code(args: &[&str]) -> u8 {
+        match run(&parse(args).expect("arguments")) {
+            Ok(()) => panic!("command should fail"),
+            Err(failure) => failure.exit,
+        }
+    }
+
+    #[test]
+    fn options_are_accepted_anywhere() {
+        let cli = parse(&[
+            "solve",
+            "--json",
+            "2017",
+            "practice",
+            "-o",
+            "a.out",
+            "a_example",
+        ])
+        .expect("valid");
+        assert!(cli.json);
+        assert_eq!(cli.output.as_deref(), Some("a.out"));
+        assert_eq!(cli.command, ["solve", "2017", "practice", "a_example"]);
+
+        let cli = parse(&["--output", "b.out", "list"]).expect("valid");
+        assert!(!cli.json);
+        assert_eq!(cli.output.as_deref(), Some("b.out"));
+        assert_eq!(cli.command, ["list"]);
+    }
+
+    #[test]
+    fn invalid_options_are_reported() {
+        assert_eq!(parse(&["-h"]).expect_err("help"), "");
+        assert_eq!(parse(&["list", "--help"]).expect_err("help"), "");
+        assert_eq!(
+            parse(&["--verbose", "list"]).expect_err("unknown"),
+            "Unknown option '--verbose'"
+        );
+        assert_eq!(
+            parse(&["solve", "-o"]).expect_err("missing value"),
+            "Missing value for -o"
+        );
+    }
+
+    #[test]
+    fn positional_checks_the_argument_count() {
+        let args = ["2021".to_string(), "qualification".to_string()];
+        let Ok([year, round]) = positional("describe", &args) else {
+            panic!("two arguments expected");
+        };
+        assert_eq!((year, round), ("2021", "qualification"));
+
+        let Err(failure) = positional::<3>("validate", &args) else {
+            panic!("three arguments expected");
+        };
+        assert_eq!(failure.exit, EXIT_USAGE);
+        assert_eq!(
+            failure.reply.payload["error"]["message"],
+            "'validate' expects 3 arguments"
+        );
+        assert_eq!(exit_code(&["list", "extra"]), EXIT_USAGE);
+        assert_eq!(exit_code(&["score", "2021", "qualification"]), EXIT_USAGE);
+    }
+
+    #[test]
+    fn usage_errors_exit_with_two() {
+        assert_eq!(exit_code(&[]), EXIT_USAGE);
+        assert_eq!(exit_code(&["rank"]), EXIT_USAGE);
+        assert_eq!(exit_code(&["-o", "a.out", "list"]), EXIT_USAGE);
+        assert_eq!(
+            exit_code(&["solve", "2013", "final", "a_example"]),
+            EXIT_USAGE
+        );
+        assert_eq!(
+            exit_code(&["solve", "2017", "practice", "z_missing"]),
+            EXIT_USAGE
+        );
+        assert_eq!(
+            exit_code(&["solve", "2017", "qualification", "me_at_the_zoo"]),
+            EXIT_USAGE
+        );
+    }
+
+    #[test]
+    fn problem_errors_map_to_exit_codes() {
+        let key = ProblemKey::from_route("2017", "practice").expect("registered");
+        let exit = |error: ProblemError, fallback| Failure::problem(error, fallback).exit;
+
+        let unknown = key
+            .score_submission("z_missing", "0\n")
+            .expect_err("unknown");
+        assert_eq!(exit(unknown, EXIT_REJECTED), EXIT_USAGE);
+        let other = ProblemKey::from_route("2017", "qualification").expect("registered");
+        let no_solver = other.solve("me_at_the_zoo").expect_err("no solver");
+        assert_eq!(exit(no_solver, EXIT_INTERNAL), EXIT_USAGE);
+
+        // The same rejection is the user's fault for a submitted file and an
+        // internal error when the bundled solver produced it.
+        let rejected = || {
+            key.score_submission("a_example", "1\n0 0 3 3\n")
+                .expect_err("out of bounds")
+        };
+        assert_eq!(exit(rejected(), EXIT_REJECTED), EXIT_REJECTED);
+        assert_eq!(exit(rejected(), EXIT_INTERNAL), EXIT_INTERNAL);
+    }
+
+    #[test]
+    fn unreadable_files_are_internal_errors() {
+        let missing = std::env::temp_dir().join("hashy-missing-submission.out");
+        let missing = missing.to_str().expect("path");
+        assert_eq!(
+            exit_code(&["validate", "2017", "practice", missing]),
+            EXIT_INTERNAL
+        );
+    }
+}
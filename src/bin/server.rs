@@ -0,0 +1,260 @@
+//! Standalone HTTP server for the scoring API, for self-hosting or offline
+//! contest rooms where the Vercel runtime is not available.
+//!
+//! Serves the same routes as `api/handler.rs` through [`hashy::api::handle`].
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use http::StatusCode;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use hyper_util::server::graceful::GracefulShutdown;
+use tokio::net::TcpListener;
+
+use hashy::api::{self, ApiResponse};
+
+const USAGE: &str = "\
+Usage: server [--listen ADDR] [--workers N]
+
+Options:
+  --listen ADDR   Address to bind (env HASHY_LISTEN, default 127.0.0.1:3000)
+  --workers N     Worker threads (env HASHY_WORKERS, default: one per CPU)";
+
+const DEFAULT_LISTEN: &str = "127.0.0.1:3000";
+
+/// How long open connections may take to finish once shutdown starts.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(30);
+
+/// Largest request body accepted, enough for a whole round of submissions.
+const MAX_BODY_BYTES: usize = 64 * 1024 * 1024;
+
+#[derive(Debug)]
+struct Config {
+    listen: SocketAddr,
+    workers: usize,
+}
+
+impl Config {
+    /// Reads the configuration from command-line flags, falling back to the
+    /// `HASHY_LISTEN` and `HASHY_WORKERS` variables looked up with `env`.
+    fn from_args(
+        mut args: impl Iterator<Item = String>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, String> {
+        let mut listen = env("HASHY_LISTEN");
+        let mut workers = env("HASHY_WORKERS");
+        while let Some(flag) = args.next() {
+            let slot = match flag.as_str() {
+                "--listen" => &mut listen,
+                "--workers" => &mut workers,
+                "-h" | "--help" => return Err(String::new()),
+                other => return Err(format!("Unknown argument '{other}'")),
+            };
+            *slot = Some(
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {flag}"))?,
+            );
+        }
+
+        let listen = listen.as_deref().unwrap_or(DEFAULT_LISTEN);
+        let listen = listen
+            .parse::<SocketAddr>()
+            .map_err(|_err| format!("Invalid listen address '{listen}'"))?;
+        let workers = match workers {
+            Some(value) => match value.parse::<usize>() {
+                Ok(count) if count > 0 => count,
+                _ => return Err(format!("Invalid worker count '{value}'")),
+            },
+            None => std::thread::available_parallelism().map_or(1, |count| count.get()),
+        };
+        Ok(Self { listen, workers })
+    }
+}
+
+fn main() {
+    let config = match Config::from_args(std::env::args().skip(1), |name| std::env::var(name).ok())
+    {
+        Ok(config) => config,
+        Err(message) if message.is_empty() => {
+            println!("{USAGE}");
+            return;
+        }
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(config.workers)
+        .enable_all()
+        .build();
+    let result = runtime.and_then(|runtime| runtime.block_on(serve(config)));
+    if let Err(error) = result {
+        eprintln!("server error: {error}");
+        std::process::exit(1);
+    }
+}
+
+async fn serve(config: Config) -> std::io::Result<()> {
+    let listener = TcpListener::bind(config.listen).await?;
+    eprintln!(
+        "Scoring API listening on http://{address} with {workers} workers",
+        address = listener.local_addr()?,
+        workers = config.workers
+    );
+
+    let graceful = GracefulShutdown::new();
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let stream = match accepted {
+                    Ok((stream, _peer)) => stream,
+                    Err(error) => {
+                        eprintln!("accept failed: {error}");
+                        continue;
+                    }
+                };
+                let connection = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service_fn(respond));
+                let connection = graceful.watch(connection);
+                tokio::spawn(async move {
+                    if let Err(error) = connection.await {
+                        eprintln!("connection error: {error}");
+                    }
+                });
+            }
+            () = &mut shutdown => break,
+        }
+    }
+
+    eprintln!("Shutting down, waiting for open connections");
+    drop(listener);
+    tokio::select! {
+        () = graceful.shutdown() => {}
+        () = tokio::time::sleep(SHUTDOWN_GRACE) => {
+            eprintln!("Connections still open after {SHUTDOWN_GRACE:?}, exiting anyway");
+        }
+    }
+    Ok(())
+}
+
+async fn respond(request: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let reply = match Limited::new(body, MAX_BODY_BYTES).collect().await {
+        // Scoring is CPU-bound and may spawn threads, so keep it off the
+        // async workers.
+        Ok(body) => {
+            let body = body.to_bytes();
+            tokio::task::spawn_blocking(move || {
+                api::handle(&parts.method, &parts.uri, &parts.headers, &body)
+            })
+            .await
+            .unwrap_or_else(|_panic| {
+                ApiResponse::error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal-error",
+                    "Scoring failed unexpectedly",
+                )
+            })
+        }
+        Err(error) if error.is::<LengthLimitError>() => ApiResponse::error(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "payload-too-large",
+            format!("Request body exceeds {MAX_BODY_BYTES} bytes"),
+        ),
+        Err(error) => ApiResponse::error(
+            StatusCode::BAD_REQUEST,
+            "invalid-body",
+            format!("Cannot read request body: {error}"),
+        ),
+    };
+    let response = Response::builder()
+        .status(reply.status)
+        .header("Content-Type", "application/json")
+        .body(Full::new(Bytes::from(reply.body())))
+        .expect("status and header are valid");
+    Ok(response)
+}
+
+/// Resolves on Ctrl-C, or on SIGTERM where available.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(error) = tokio::signal::ctrl_c().await {
+            eprintln!("cannot listen for Ctrl-C: {error}");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            }
+            Err(error) => {
+                eprintln!("cannot listen for SIGTERM: {error}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, DEFAULT_LISTEN};
+
+    fn parse(args: &[&str], env: &[(&str, &str)]) -> Result<Config, String> {
+        let args = args.iter().map(|arg| arg.to_string());
+        Config::from_args(args, |name| {
+            env.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn defaults_apply_without_flags_or_environment() {
+        let config = parse(&[], &[]).expect("defaults");
+        assert_eq!(config.listen, DEFAULT_LISTEN.parse().expect("address"));
+        assert!(config.workers >= 1);
+    }
+
+    #[test]
+    fn flags_take_precedence_over_the_environment() {
+        let env = [("HASHY_LISTEN", "0.0.0.0:8080"), ("HASHY_WORKERS", "3")];
+        let config = parse(&[], &env).expect("environment");
+        assert_eq!(config.listen, "0.0.0.0:8080".parse().expect("address"));
+        assert_eq!(config.workers, 3);
+
+        let config = parse(&["--listen", "127.0.0.1:9000", "--workers", "5"], &env).expect("flags");
+        assert_eq!(config.listen, "127.0.0.1:9000".parse().expect("address"));
+        assert_eq!(config.workers, 5);
+    }
+
+    #[test]
+    fn invalid_arguments_are_reported() {
+        assert_eq!(parse(&["--help"], &[]).expect_err("help"), "");
+        assert!(parse(&["--port", "1"], &[]).is_err());
+        assert!(parse(&["--listen"], &[]).is_err());
+        assert!(parse(&["--listen", "localhost"], &[]).is_err());
+        assert!(parse(&["--workers", "0"], &[]).is_err());
+        assert!(parse(&[], &[("HASHY_WORKERS", "many")]).is_err());
+    }
+}
@@ -30,7 +30,7 @@ export const collections = {
 				import: "default",
 			});
 			const inputsGlob = import.meta.glob(
-				["./hashcodes/**/*.in", "./hashcodes/**/*.in/*.txt"],
+				["./hashcodes/**/*.in", "./hashcodes/**/*.in/*"],
 				{ query: "?url" },
 			);
 
@@ -0,0 +1,82 @@
+//! Google Hash Code 2014 Final Round ("Street View Routing") implementation.
+
+#[path = "hashcode_2014_final_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2014_final_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2014_final_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2014_final_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2014 final round.
+pub struct HashCode2014Final;
+
+impl Problem for HashCode2014Final {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2014_final_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2014"
+    }
+
+    fn round(&self) -> &'static str {
+        "final"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["final_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
+
+/// A street between two junctions as listed in the input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Street {
+    pub from: usize,
+    pub to: usize,
+    pub bidirectional: bool,
+    /// Seconds needed to drive along the street.
+    pub cost: u32,
+    /// Length in meters, which is what the score rewards.
+    pub length: u32,
+}
+
+impl Street {
+    /// Returns true when a car may drive from `a` to `b` along this street.
+    pub fn connects(&self, a: usize, b: usize) -> bool {
+        (self.from == a && self.to == b) || (self.bidirectional && self.from == b && self.to == a)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+use super::Street;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProblemInput {
+    /// `(latitude, longitude)` for every junction.
+    pub junctions: Vec<(f64, f64)>,
+    pub streets: Vec<Street>,
+    pub time_limit: u32,
+    pub car_count: usize,
+    pub start_junction: usize,
+    /// Street indices touching each junction, in either direction.
+    pub adjacency: Vec<Vec<usize>>,
+}
+
+impl ProblemInput {
+    /// Finds the quickest street a car can take from `a` to `b`.
+    pub fn street_between(&self, a: usize, b: usize) -> Option<usize> {
+        self.adjacency[a]
+            .iter()
+            .copied()
+            .filter(|&index| self.streets[index].connects(a, b))
+            .min_by_key(|&index| self.streets[index].cost)
+    }
+}
+
+pub static DATASETS: &[Dataset] = &[Dataset {
+    name: "paris_54000",
+    file: "hashcode_2014_final_round.in",
+    aliases: &["paris"],
+    contents: include_str!("hashcode_2014_final_round.in"),
+}];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2014", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let junction_count = tokens.parse::<usize>("junctions")?;
+    let street_count = tokens.parse::<usize>("streets")?;
+    let time_limit = tokens.parse::<u32>("time_limit")?;
+    let car_count = tokens.parse::<usize>("cars")?;
+    let start_junction = tokens.parse::<usize>("start_junction")?;
+
+    if start_junction >= junction_count {
+        return Err(ProblemError::with_details(
+            "invalid-start-junction",
+            format!("Start junction {start_junction} does not exist"),
+            serde_json::json!({ "start_junction": start_junction, "junctions": junction_count }),
+        ));
+    }
+
+    let mut junctions = Vec::with_capacity(junction_count);
+    for _ in 0..junction_count {
+        let latitude = tokens.parse::<f64>("latitude")?;
+        let longitude = tokens.parse::<f64>("longitude")?;
+        junctions.push((latitude, longitude));
+    }
+
+    let mut streets = Vec::with_capacity(street_count);
+    let mut adjacency = vec![Vec::new(); junction_count];
+    for index in 0..street_count {
+        let from = tokens.parse::<usize>("street_from")?;
+        let to = tokens.parse::<usize>("street_to")?;
+        let direction = tokens.parse::<u8>("street_direction")?;
+        let cost = tokens.parse::<u32>("street_cost")?;
+        let length = tokens.parse::<u32>("street_length")?;
+        if from >= junction_count || to >= junction_count {
+            return Err(ProblemError::with_details(
+                "invalid-street-junction",
+                format!("Street {index} references a junction that does not exist"),
+                serde_json::json!({ "street": index, "from": from, "to": to }),
+            ));
+        }
+        let bidirectional = match direction {
+            1 => false,
+            2 => true,
+            other => {
+                return Err(ProblemError::with_details(
+                    "invalid-street-direction",
+                    format!("Street {index} has direction {other} but expected 1 or 2"),
+                    serde_json::json!({ "street": index, "direction": other }),
+                ))
+            }
+        };
+        adjacency[from].push(index);
+        if to != from {
+            adjacency[to].push(index);
+        }
+        streets.push(Street {
+            from,
+            to,
+            bidirectional,
+            cost,
+            length,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        junctions,
+        streets,
+        time_limit,
+        car_count,
+        start_junction,
+        adjacency,
+    })
+}
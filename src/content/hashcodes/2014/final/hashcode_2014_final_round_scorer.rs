@@ -0,0 +1,137 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub streets_covered: usize,
+    /// Seconds of driving used by each car.
+    pub time_used: Vec<u32>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "total_score": self.total_score,
+            "streets_covered": self.streets_covered,
+            "time_used": self.time_used,
+        })
+    }
+}
+
+/// Replays every car's itinerary as described in the statement:
+/// - the submission describes exactly `C` cars, all starting at junction `S`
+/// - consecutive junctions must be joined by a street that may be driven in
+///   that direction
+/// - the summed street costs of a car cannot exceed the time budget `T`
+///
+/// The score is the total length of distinct streets visited by any car, so a
+/// street driven several times (or in both directions) only counts once.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    if submission.itineraries.len() != input.car_count {
+        return Err(ProblemError::with_details(
+            "car-count-mismatch",
+            format!(
+                "Submission describes {actual} cars but the input has {expected}",
+                actual = submission.itineraries.len(),
+                expected = input.car_count
+            ),
+            serde_json::json!({
+                "expected": input.car_count,
+                "actual": submission.itineraries.len(),
+            }),
+        ));
+    }
+
+    let mut covered = vec![false; input.streets.len()];
+    let mut total_score = 0u64;
+    let mut streets_covered = 0usize;
+    let mut time_used = Vec::with_capacity(input.car_count);
+
+    for (car, itinerary) in submission.itineraries.iter().enumerate() {
+        if itinerary[0] != input.start_junction {
+            return Err(ProblemError::with_details(
+                "invalid-start",
+                format!(
+                    "Car {car} starts at junction {actual} instead of {expected}",
+                    actual = itinerary[0],
+                    expected = input.start_junction
+                ),
+                serde_json::json!({
+                    "car": car,
+                    "expected": input.start_junction,
+                    "actual": itinerary[0],
+                }),
+            ));
+        }
+        if let Some(step) = itinerary
+            .iter()
+            .position(|&junction| junction >= input.junctions.len())
+        {
+            return Err(ProblemError::with_details(
+                "unknown-junction",
+                format!(
+                    "Car {car} visits junction {junction} which does not exist",
+                    junction = itinerary[step]
+                ),
+                serde_json::json!({
+                    "car": car,
+                    "step": step,
+                    "junction": itinerary[step],
+                    "junctions": input.junctions.len(),
+                }),
+            ));
+        }
+
+        let mut elapsed = 0u32;
+        for (step, pair) in itinerary.windows(2).enumerate() {
+            let (from, to) = (pair[0], pair[1]);
+            let street = input.street_between(from, to).ok_or_else(|| {
+                ProblemError::with_details(
+                    "no-street",
+                    format!("Car {car} cannot drive from junction {from} to junction {to}"),
+                    serde_json::json!({ "car": car, "step": step + 1, "from": from, "to": to }),
+                )
+            })?;
+            elapsed += input.streets[street].cost;
+            if elapsed > input.time_limit {
+                return Err(ProblemError::with_details(
+                    "time-limit-exceeded",
+                    format!(
+                        "Car {car} needs {elapsed} seconds but only {limit} are available",
+                        limit = input.time_limit
+                    ),
+                    serde_json::json!({
+                        "car": car,
+                        "step": step + 1,
+                        "elapsed": elapsed,
+                        "time_limit": input.time_limit,
+                    }),
+                ));
+            }
+            if !covered[street] {
+                covered[street] = true;
+                streets_covered += 1;
+                total_score += u64::from(input.streets[street].length);
+            }
+        }
+        time_used.push(elapsed);
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        streets_covered,
+        time_used,
+    })
+}
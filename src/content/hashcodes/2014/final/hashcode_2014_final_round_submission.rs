@@ -0,0 +1,45 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Junctions visited by each car, in driving order.
+    pub itineraries: Vec<Vec<usize>>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let car_count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-car-count",
+            "Submission must start with the number of cars",
+        )
+    })?;
+    let car_count = car_count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-car-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": car_count }),
+        )
+    })?;
+
+    let mut itineraries = Vec::with_capacity(car_count.min(1 << 20));
+    for car in 0..car_count {
+        let length = tokens.parse::<usize>("itinerary_length")?;
+        if length == 0 {
+            return Err(ProblemError::with_details(
+                "empty-itinerary",
+                format!("Car {car} must visit at least its starting junction"),
+                serde_json::json!({ "car": car, "line": tokens.line() }),
+            ));
+        }
+        let mut junctions = Vec::with_capacity(length.min(1 << 20));
+        for _ in 0..length {
+            junctions.push(tokens.parse::<usize>("junction")?);
+        }
+        itineraries.push(junctions);
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { itineraries })
+}
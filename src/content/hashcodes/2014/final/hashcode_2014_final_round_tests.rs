@@ -0,0 +1,95 @@
+use super::input::parse_input;
+use super::submission::parse_submission;
+use super::HashCode2014Final;
+use crate::hashcodes::test_support::Fixture;
+
+// Four junctions in a square: 0 -> 1 is one-way, every other street is
+// two-way. Car budget is 10 seconds.
+const SQUARE: &str = "4 4 10 2 0
+48.0 2.0
+48.0 2.1
+48.1 2.1
+48.1 2.0
+0 1 1 3 100
+1 2 2 4 200
+2 3 2 2 50
+3 0 2 5 70
+";
+
+fn square() -> Fixture<HashCode2014Final> {
+    Fixture::inline(HashCode2014Final, SQUARE)
+}
+
+#[test]
+fn official_dataset_parses() {
+    let parsed = Fixture::dataset(HashCode2014Final, "hashcode_2014_final_round.in").input;
+    assert_eq!(parsed.junctions.len(), 11348);
+    assert_eq!(parsed.streets.len(), 17958);
+    assert_eq!(parsed.car_count, 8);
+    assert_eq!(parsed.start_junction, 4516);
+}
+
+#[test]
+fn parse_input_rejects_invalid_direction() {
+    let err = parse_input("2 1 10 1 0\n0 0\n1 1\n0 1 3 1 1\n").expect_err("direction");
+    assert_eq!(err.code, "invalid-street-direction");
+}
+
+#[test]
+fn parse_input_rejects_unknown_junction() {
+    let err = parse_input("2 1 10 1 0\n0 0\n1 1\n0 2 1 1 1\n").expect_err("junction");
+    assert_eq!(err.code, "invalid-street-junction");
+}
+
+#[test]
+fn submission_parser_detects_missing_count() {
+    let err = parse_submission("").expect_err("should fail");
+    assert_eq!(err.code, "missing-car-count");
+}
+
+#[test]
+fn submission_parser_detects_truncated_itinerary() {
+    let err = parse_submission("1\n3\n0\n1\n").expect_err("truncated");
+    assert_eq!(err.code, "missing-value");
+}
+
+#[test]
+fn submission_parser_does_not_trust_declared_counts() {
+    for huge in [
+        "18446744073709551615",
+        "4294967296",
+        "1\n18446744073709551615",
+    ] {
+        let err = parse_submission(huge).expect_err("truncated");
+        assert_eq!(err.code, "missing-value");
+    }
+}
+
+#[test]
+fn scoring_counts_each_street_once() {
+    let breakdown = square().score("2\n3\n0\n1\n2\n4\n0\n3\n2\n3\n");
+    assert_eq!(breakdown.total_score, 100 + 200 + 70 + 50);
+    assert_eq!(breakdown.streets_covered, 4);
+    assert_eq!(breakdown.time_used, vec![7, 9]);
+}
+
+#[test]
+fn scoring_rejects_one_way_street_driven_backwards() {
+    let square = square();
+    square.score("2\n2\n0\n3\n3\n0\n3\n0\n");
+    let err = square.score_error("2\n1\n0\n3\n0\n1\n0\n");
+    assert_eq!(err.code, "no-street");
+}
+
+#[test]
+fn scoring_rejects_time_limit_overrun() {
+    let err = square().score_error("2\n4\n0\n1\n2\n1\n1\n0\n");
+    assert_eq!(err.code, "time-limit-exceeded");
+}
+
+#[test]
+fn scoring_rejects_wrong_start_and_car_count() {
+    let square = square();
+    assert_eq!(square.score_error("2\n1\n1\n1\n0\n").code, "invalid-start");
+    assert_eq!(square.score_error("1\n1\n0\n").code, "car-count-mismatch");
+}
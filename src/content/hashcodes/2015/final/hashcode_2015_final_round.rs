@@ -0,0 +1,70 @@
+//! Google Hash Code 2015 Final Round ("Loon") implementation.
+
+#[path = "hashcode_2015_final_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2015_final_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2015_final_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2015_final_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2015 final round.
+pub struct HashCode2015Final;
+
+impl Problem for HashCode2015Final {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2015_final_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2015"
+    }
+
+    fn round(&self) -> &'static str {
+        "final"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["final_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
+
+/// Wind vector applied to a balloon at a given cell and altitude.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Wind {
+    pub delta_row: i32,
+    pub delta_col: i32,
+}
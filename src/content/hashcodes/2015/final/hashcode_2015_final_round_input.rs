@@ -0,0 +1,95 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+use super::Wind;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub rows: usize,
+    pub cols: usize,
+    pub altitudes: usize,
+    pub radius: usize,
+    pub balloons: usize,
+    pub turns: usize,
+    pub start: (usize, usize),
+    pub targets: Vec<(usize, usize)>,
+    /// Movement grids for altitudes `1..=A`, stored altitude by altitude and
+    /// row by row.
+    pub wind: Vec<Wind>,
+}
+
+impl ProblemInput {
+    /// Wind at `altitude` (1-based, as in the statement) over `(row, col)`.
+    pub fn wind_at(&self, altitude: usize, row: usize, col: usize) -> Wind {
+        self.wind[((altitude - 1) * self.rows + row) * self.cols + col]
+    }
+}
+
+pub static DATASETS: &[Dataset] = &[Dataset {
+    name: "loon",
+    file: "hashcode_2015_final_round.in",
+    aliases: &[],
+    contents: include_str!("hashcode_2015_final_round.in"),
+}];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2015", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let rows = tokens.parse::<usize>("rows")?;
+    let cols = tokens.parse::<usize>("columns")?;
+    let altitudes = tokens.parse::<usize>("altitudes")?;
+    let target_count = tokens.parse::<usize>("targets")?;
+    let radius = tokens.parse::<usize>("radius")?;
+    let balloons = tokens.parse::<usize>("balloons")?;
+    let turns = tokens.parse::<usize>("turns")?;
+    let start = (
+        tokens.parse::<usize>("start_row")?,
+        tokens.parse::<usize>("start_col")?,
+    );
+    if start.0 >= rows || start.1 >= cols {
+        return Err(ProblemError::with_details(
+            "invalid-start-cell",
+            "Starting cell lies outside the grid",
+            serde_json::json!({ "row": start.0, "col": start.1 }),
+        ));
+    }
+
+    let mut targets = Vec::with_capacity(target_count);
+    for index in 0..target_count {
+        let row = tokens.parse::<usize>("target_row")?;
+        let col = tokens.parse::<usize>("target_col")?;
+        if row >= rows || col >= cols {
+            return Err(ProblemError::with_details(
+                "invalid-target-cell",
+                format!("Target {index} lies outside the grid"),
+                serde_json::json!({ "target": index, "row": row, "col": col }),
+            ));
+        }
+        targets.push((row, col));
+    }
+
+    let mut wind = Vec::with_capacity(altitudes * rows * cols);
+    for _ in 0..altitudes * rows * cols {
+        wind.push(Wind {
+            delta_row: tokens.parse::<i32>("wind_delta_row")?,
+            delta_col: tokens.parse::<i32>("wind_delta_col")?,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        rows,
+        cols,
+        altitudes,
+        radius,
+        balloons,
+        turns,
+        start,
+        targets,
+        wind,
+    })
+}
@@ -0,0 +1,173 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    /// Turn during which each balloon left the grid, if it did.
+    pub lost_at: Vec<Option<usize>>,
+    /// Number of covered target cells at the end of each turn.
+    pub coverage_per_turn: Vec<usize>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "total_score": self.total_score,
+            "lost_balloons": self.lost_at.iter().filter(|turn| turn.is_some()).count(),
+            "lost_at": self.lost_at,
+            "coverage_per_turn": self.coverage_per_turn,
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Balloon {
+    altitude: usize,
+    row: usize,
+    col: usize,
+    lost: bool,
+}
+
+/// Runs the turn-by-turn simulation from the statement:
+/// - the submission has `T` lines of `B` adjustments each
+/// - a balloon on the ground may only launch (`1`) or stay (`0`); once
+///   launched its altitude must stay within `1..=A`, even after it is lost
+/// - launched balloons then follow the wind at their new altitude, wrapping
+///   around columns, and are lost for good when they leave the rows
+///
+/// At the end of every turn each target cell within distance `V` of a
+/// launched balloon scores one point, where the column distance takes the
+/// wrap-around into account.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    if submission.turns.len() != input.turns {
+        return Err(ProblemError::with_details(
+            "turn-count-mismatch",
+            format!(
+                "Submission describes {actual} turns but the simulation lasts {expected}",
+                actual = submission.turns.len(),
+                expected = input.turns
+            ),
+            serde_json::json!({ "expected": input.turns, "actual": submission.turns.len() }),
+        ));
+    }
+
+    let mut target_at = vec![None; input.rows * input.cols];
+    for (index, &(row, col)) in input.targets.iter().enumerate() {
+        target_at[row * input.cols + col] = Some(index);
+    }
+    let radius = input.radius as i64;
+    let mut covered_turn = vec![usize::MAX; input.targets.len()];
+
+    let mut balloons = vec![
+        Balloon {
+            altitude: 0,
+            row: input.start.0,
+            col: input.start.1,
+            lost: false,
+        };
+        input.balloons
+    ];
+    let mut lost_at = vec![None; input.balloons];
+    let mut coverage_per_turn = Vec::with_capacity(input.turns);
+    let mut total_score = 0u64;
+
+    for (turn, adjustments) in submission.turns.iter().enumerate() {
+        if adjustments.len() != input.balloons {
+            return Err(ProblemError::with_details(
+                "balloon-count-mismatch",
+                format!(
+                    "Turn {turn} adjusts {actual} balloons but there are {expected}",
+                    actual = adjustments.len(),
+                    expected = input.balloons
+                ),
+                serde_json::json!({
+                    "turn": turn,
+                    "expected": input.balloons,
+                    "actual": adjustments.len(),
+                }),
+            ));
+        }
+
+        let mut covered = 0usize;
+        for (index, (balloon, &delta)) in balloons.iter_mut().zip(adjustments).enumerate() {
+            let launched = balloon.altitude > 0;
+            let altitude = balloon.altitude as i64 + i64::from(delta);
+            let allowed = if launched {
+                (1..=input.altitudes as i64).contains(&altitude)
+            } else {
+                delta >= 0 && altitude <= input.altitudes as i64
+            };
+            if !allowed {
+                return Err(ProblemError::with_details(
+                    "invalid-altitude",
+                    format!(
+                        "Balloon {index} cannot move from altitude {from} to {altitude} in turn {turn}",
+                        from = balloon.altitude
+                    ),
+                    serde_json::json!({
+                        "turn": turn,
+                        "balloon": index,
+                        "from": balloon.altitude,
+                        "to": altitude,
+                        "max_altitude": input.altitudes,
+                    }),
+                ));
+            }
+            balloon.altitude = altitude as usize;
+            if balloon.lost || balloon.altitude == 0 {
+                continue;
+            }
+
+            let wind = input.wind_at(balloon.altitude, balloon.row, balloon.col);
+            let row = balloon.row as i64 + i64::from(wind.delta_row);
+            if row < 0 || row >= input.rows as i64 {
+                balloon.lost = true;
+                lost_at[index] = Some(turn);
+                continue;
+            }
+            balloon.row = row as usize;
+            balloon.col = (balloon.col as i64 + i64::from(wind.delta_col))
+                .rem_euclid(input.cols as i64) as usize;
+
+            for delta_row in -radius..=radius {
+                let row = balloon.row as i64 + delta_row;
+                if row < 0 || row >= input.rows as i64 {
+                    continue;
+                }
+                for delta_col in -radius..=radius {
+                    if delta_row * delta_row + delta_col * delta_col > radius * radius {
+                        continue;
+                    }
+                    let col = (balloon.col as i64 + delta_col).rem_euclid(input.cols as i64);
+                    let cell = row as usize * input.cols + col as usize;
+                    if let Some(target) = target_at[cell] {
+                        if covered_turn[target] != turn {
+                            covered_turn[target] = turn;
+                            covered += 1;
+                        }
+                    }
+                }
+            }
+        }
+        total_score += covered as u64;
+        coverage_per_turn.push(covered);
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        lost_at,
+        coverage_per_turn,
+    })
+}
@@ -0,0 +1,41 @@
+use crate::hashcodes::error::ProblemError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Altitude adjustment of every balloon, turn by turn.
+    pub turns: Vec<Vec<i8>>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut turns = Vec::new();
+
+    for (index, line) in raw.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let adjustments: Result<Vec<i8>, ProblemError> = line
+            .split_whitespace()
+            .enumerate()
+            .map(|(balloon, value)| match value {
+                "-1" => Ok(-1),
+                "0" => Ok(0),
+                "1" => Ok(1),
+                other => Err(ProblemError::with_details(
+                    "invalid-adjustment",
+                    format!(
+                        "Adjustment '{other}' for balloon {balloon} on line {line_no} must be -1, 0 or 1",
+                        line_no = index + 1
+                    ),
+                    serde_json::json!({
+                        "line": index + 1,
+                        "balloon": balloon,
+                        "value": other,
+                    }),
+                )),
+            })
+            .collect();
+        turns.push(adjustments?);
+    }
+
+    Ok(ProblemSubmission { turns })
+}
@@ -0,0 +1,86 @@
+use super::input::parse_input;
+use super::submission::parse_submission;
+use super::HashCode2015Final;
+use crate::hashcodes::test_support::Fixture;
+
+// The example from the statement: 3 rows, 5 columns, 3 altitudes, two
+// targets, radius 1, one balloon and 5 turns.
+const EXAMPLE: &str = "3 5 3
+2 1 1 5
+1 2
+0 2
+0 4
+0 1 0 1 0 1 0 1 0 1
+0 1 0 1 0 1 0 1 0 1
+0 1 0 1 0 1 0 1 0 1
+-1 0 -1 0 -1 0 -1 0 -1 0
+-1 0 -1 0 -1 0 -1 0 -1 0
+-1 0 -1 0 -1 0 -1 0 -1 0
+0 1 0 1 0 1 0 2 0 1
+0 2 0 1 0 2 0 3 0 2
+0 1 0 1 0 1 0 2 0 1
+";
+
+fn example() -> Fixture<HashCode2015Final> {
+    Fixture::inline(HashCode2015Final, EXAMPLE)
+}
+
+#[test]
+fn official_dataset_parses() {
+    let parsed = Fixture::dataset(HashCode2015Final, "loon").input;
+    assert_eq!((parsed.rows, parsed.cols, parsed.altitudes), (75, 300, 8));
+    assert_eq!(parsed.targets.len(), 2250);
+    assert_eq!((parsed.balloons, parsed.turns), (53, 400));
+    assert_eq!(parsed.wind.len(), 75 * 300 * 8);
+}
+
+#[test]
+fn parse_input_rejects_target_outside_grid() {
+    let err = parse_input("1 1 1\n1 0 1 1\n0 0\n3 0\n0 0\n").expect_err("target");
+    assert_eq!(err.code, "invalid-target-cell");
+}
+
+#[test]
+fn submission_parser_rejects_invalid_adjustment() {
+    let err = parse_submission("1\n2\n").expect_err("should fail");
+    assert_eq!(err.code, "invalid-adjustment");
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("1\n1\n1\n0\n0\n");
+    assert_eq!(breakdown.total_score, 5);
+    assert_eq!(breakdown.coverage_per_turn, vec![0, 2, 1, 1, 1]);
+    assert_eq!(breakdown.lost_at, vec![None]);
+}
+
+#[test]
+fn scoring_reports_lost_balloons() {
+    // Altitude 2 blows north: the balloon leaves row 0 during turn 2.
+    let breakdown = example().score("1\n1\n0\n0\n-1\n");
+    assert_eq!(breakdown.lost_at, vec![Some(2)]);
+    assert_eq!(breakdown.coverage_per_turn[3..], [0, 0]);
+}
+
+#[test]
+fn scoring_rejects_invalid_altitudes() {
+    let example = example();
+    let err = example.score_error("-1\n0\n0\n0\n0\n");
+    assert_eq!(err.code, "invalid-altitude");
+
+    let err = example.score_error("1\n-1\n0\n0\n0\n");
+    assert_eq!(err.code, "invalid-altitude");
+
+    let err = example.score_error("1\n1\n1\n1\n0\n");
+    assert_eq!(err.code, "invalid-altitude");
+}
+
+#[test]
+fn scoring_rejects_wrong_dimensions() {
+    let example = example();
+    let err = example.score_error("1\n1\n");
+    assert_eq!(err.code, "turn-count-mismatch");
+
+    let err = example.score_error("1 0\n1\n1\n0\n0\n");
+    assert_eq!(err.code, "balloon-count-mismatch");
+}
@@ -0,0 +1,79 @@
+//! Google Hash Code 2015 Qualification Round ("Optimize a Data Center") implementation.
+
+#[path = "hashcode_2015_qualification_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2015_qualification_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2015_qualification_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2015_qualification_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2015 qualification round.
+pub struct HashCode2015Qualification;
+
+impl Problem for HashCode2015Qualification {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2015_qualification_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2015"
+    }
+
+    fn round(&self) -> &'static str {
+        "qualification"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["qualification_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
+
+/// A server as described in the input file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Server {
+    /// Number of consecutive slots the server occupies.
+    pub size: usize,
+    pub capacity: u32,
+}
+
+/// Where a submission puts a single server.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Placement {
+    pub row: usize,
+    pub slot: usize,
+    pub pool: usize,
+}
@@ -0,0 +1,65 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+use super::Server;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub rows: usize,
+    pub slots: usize,
+    pub pools: usize,
+    /// `(row, slot)` pairs that cannot hold a server.
+    pub unavailable: Vec<(usize, usize)>,
+    pub servers: Vec<Server>,
+}
+
+pub static DATASETS: &[Dataset] = &[Dataset {
+    name: "dc",
+    file: "hashcode_2015_qualification_round.in",
+    aliases: &["data_center"],
+    contents: include_str!("hashcode_2015_qualification_round.in"),
+}];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2015", "qualification", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let rows = tokens.parse::<usize>("rows")?;
+    let slots = tokens.parse::<usize>("slots")?;
+    let unavailable_count = tokens.parse::<usize>("unavailable_slots")?;
+    let pools = tokens.parse::<usize>("pools")?;
+    let server_count = tokens.parse::<usize>("servers")?;
+
+    let mut unavailable = Vec::with_capacity(unavailable_count);
+    for index in 0..unavailable_count {
+        let row = tokens.parse::<usize>("unavailable_row")?;
+        let slot = tokens.parse::<usize>("unavailable_slot")?;
+        if row >= rows || slot >= slots {
+            return Err(ProblemError::with_details(
+                "invalid-unavailable-slot",
+                format!("Unavailable slot {index} lies outside the data center"),
+                serde_json::json!({ "index": index, "row": row, "slot": slot }),
+            ));
+        }
+        unavailable.push((row, slot));
+    }
+
+    let mut servers = Vec::with_capacity(server_count);
+    for _ in 0..server_count {
+        let size = tokens.parse::<usize>("server_size")?;
+        let capacity = tokens.parse::<u32>("server_capacity")?;
+        servers.push(Server { size, capacity });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        rows,
+        slots,
+        pools,
+        unavailable,
+        servers,
+    })
+}
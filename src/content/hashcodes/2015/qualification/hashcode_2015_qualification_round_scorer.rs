@@ -0,0 +1,196 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Guaranteed capacity of a single pool.
+#[derive(Clone, Debug)]
+pub struct PoolCapacity {
+    pub pool: usize,
+    pub total_capacity: u64,
+    pub guaranteed_capacity: u64,
+    /// Row whose failure removes the most capacity from this pool.
+    pub worst_row: Option<usize>,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub placed_servers: usize,
+    pub bottleneck_pool: Option<usize>,
+    pub pools: Vec<PoolCapacity>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let pools: Vec<serde_json::Value> = self
+            .pools
+            .iter()
+            .map(|pool| {
+                serde_json::json!({
+                    "pool": pool.pool,
+                    "total_capacity": pool.total_capacity,
+                    "guaranteed_capacity": pool.guaranteed_capacity,
+                    "worst_row": pool.worst_row,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "placed_servers": self.placed_servers,
+            "bottleneck_pool": self.bottleneck_pool,
+            "pools": pools,
+        })
+    }
+}
+
+/// Validates the layout and computes the official score:
+/// - the submission lists exactly one line per server
+/// - a placed server occupies `size` consecutive slots starting at its slot,
+///   all inside the row, none unavailable and none used by another server
+/// - every placed server is assigned to an existing pool
+///
+/// The guaranteed capacity of a pool is its total capacity minus the capacity
+/// it keeps in the single row whose failure hurts it most. The score is the
+/// lowest guaranteed capacity across all pools.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    if submission.placements.len() != input.servers.len() {
+        return Err(ProblemError::with_details(
+            "server-count-mismatch",
+            format!(
+                "Submission has {actual} lines but the input lists {expected} servers",
+                actual = submission.placements.len(),
+                expected = input.servers.len()
+            ),
+            serde_json::json!({
+                "expected": input.servers.len(),
+                "actual": submission.placements.len(),
+            }),
+        ));
+    }
+
+    // `None` for free slots, `Some(None)` for unavailable ones and
+    // `Some(Some(server))` once a server is placed.
+    let mut grid: Vec<Vec<Option<Option<usize>>>> = vec![vec![None; input.slots]; input.rows];
+    for &(row, slot) in &input.unavailable {
+        grid[row][slot] = Some(None);
+    }
+
+    let mut capacity_per_row = vec![vec![0u64; input.rows]; input.pools];
+    let mut placed_servers = 0usize;
+
+    for (server_index, placement) in submission.placements.iter().enumerate() {
+        let placement = match placement {
+            Some(placement) => placement,
+            None => continue,
+        };
+        let server = input.servers[server_index];
+        if placement.pool >= input.pools {
+            return Err(ProblemError::with_details(
+                "invalid-pool",
+                format!(
+                    "Server {server_index} is assigned to pool {pool} but only {pools} pools exist",
+                    pool = placement.pool,
+                    pools = input.pools
+                ),
+                serde_json::json!({
+                    "server": server_index,
+                    "pool": placement.pool,
+                    "pools": input.pools,
+                }),
+            ));
+        }
+        if placement.row >= input.rows
+            || placement.slot >= input.slots
+            || server.size > input.slots - placement.slot
+        {
+            return Err(ProblemError::with_details(
+                "server-out-of-bounds",
+                format!("Server {server_index} does not fit inside the data center"),
+                serde_json::json!({
+                    "server": server_index,
+                    "row": placement.row,
+                    "slot": placement.slot,
+                    "size": server.size,
+                    "rows": input.rows,
+                    "slots": input.slots,
+                }),
+            ));
+        }
+        let cells = &mut grid[placement.row][placement.slot..placement.slot + server.size];
+        for (offset, cell) in cells.iter_mut().enumerate() {
+            let slot = placement.slot + offset;
+            match *cell {
+                None => *cell = Some(Some(server_index)),
+                Some(None) => {
+                    return Err(ProblemError::with_details(
+                        "unavailable-slot",
+                        format!(
+                            "Server {server_index} covers unavailable slot {slot} in row {row}",
+                            row = placement.row
+                        ),
+                        serde_json::json!({
+                            "server": server_index,
+                            "row": placement.row,
+                            "slot": slot,
+                        }),
+                    ));
+                }
+                Some(Some(other)) => {
+                    return Err(ProblemError::with_details(
+                        "overlapping-servers",
+                        format!("Server {server_index} overlaps with server {other}"),
+                        serde_json::json!({
+                            "server": server_index,
+                            "other_server": other,
+                            "row": placement.row,
+                            "slot": slot,
+                        }),
+                    ));
+                }
+            }
+        }
+        capacity_per_row[placement.pool][placement.row] += u64::from(server.capacity);
+        placed_servers += 1;
+    }
+
+    let pools: Vec<PoolCapacity> = capacity_per_row
+        .iter()
+        .enumerate()
+        .map(|(pool, rows)| {
+            let total_capacity: u64 = rows.iter().sum();
+            let worst_row = rows
+                .iter()
+                .enumerate()
+                .max_by_key(|&(row, capacity)| (*capacity, std::cmp::Reverse(row)))
+                .map(|(row, _)| row);
+            let lost = worst_row.map_or(0, |row| rows[row]);
+            PoolCapacity {
+                pool,
+                total_capacity,
+                guaranteed_capacity: total_capacity - lost,
+                worst_row,
+            }
+        })
+        .collect();
+
+    let bottleneck = pools
+        .iter()
+        .min_by_key(|pool| (pool.guaranteed_capacity, pool.pool));
+
+    Ok(ScoreBreakdown {
+        total_score: bottleneck.map_or(0, |pool| pool.guaranteed_capacity),
+        placed_servers,
+        bottleneck_pool: bottleneck.map(|pool| pool.pool),
+        pools,
+    })
+}
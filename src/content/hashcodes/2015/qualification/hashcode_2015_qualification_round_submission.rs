@@ -0,0 +1,58 @@
+use crate::hashcodes::error::ProblemError;
+
+use super::Placement;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// One entry per server; `None` when the server is left out (`x`).
+    pub placements: Vec<Option<Placement>>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut placements = Vec::new();
+
+    for (index, line) in raw.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if trimmed == "x" {
+            placements.push(None);
+            continue;
+        }
+        let values: Vec<&str> = trimmed.split_whitespace().collect();
+        if values.len() != 3 {
+            return Err(ProblemError::with_details(
+                "invalid-placement",
+                format!(
+                    "Line {line_no} must be 'x' or contain row, slot and pool",
+                    line_no = index + 1
+                ),
+                serde_json::json!({ "line": index + 1, "content": line }),
+            ));
+        }
+        let numbers: Result<Vec<usize>, ProblemError> = values
+            .iter()
+            .map(|value| {
+                value.parse::<usize>().map_err(|_err| {
+                    ProblemError::with_details(
+                        "invalid-number",
+                        format!(
+                            "Value '{value}' on line {line_no} is not a non-negative integer",
+                            line_no = index + 1
+                        ),
+                        serde_json::json!({ "line": index + 1, "value": value }),
+                    )
+                })
+            })
+            .collect();
+        let numbers = numbers?;
+        placements.push(Some(Placement {
+            row: numbers[0],
+            slot: numbers[1],
+            pool: numbers[2],
+        }));
+    }
+
+    Ok(ProblemSubmission { placements })
+}
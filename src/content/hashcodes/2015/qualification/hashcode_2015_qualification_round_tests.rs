@@ -0,0 +1,88 @@
+use super::input::parse_input;
+use super::submission::parse_submission;
+use super::HashCode2015Qualification;
+use crate::hashcodes::test_support::Fixture;
+
+// The example from the statement: 2 rows of 5 slots, slot (0, 0) is
+// unavailable, 2 pools and 5 servers.
+const EXAMPLE: &str = "2 5 1 2 5
+0 0
+3 10
+3 10
+2 5
+1 5
+1 1
+";
+
+fn example() -> Fixture<HashCode2015Qualification> {
+    Fixture::inline(HashCode2015Qualification, EXAMPLE)
+}
+
+#[test]
+fn official_dataset_parses() {
+    let parsed = Fixture::dataset(HashCode2015Qualification, "dc").input;
+    assert_eq!(parsed.rows, 16);
+    assert_eq!(parsed.slots, 100);
+    assert_eq!(parsed.unavailable.len(), 80);
+    assert_eq!(parsed.pools, 45);
+    assert_eq!(parsed.servers.len(), 625);
+}
+
+#[test]
+fn parse_input_rejects_unavailable_slot_outside_grid() {
+    let err = parse_input("1 1 1 1 0\n0 1\n").expect_err("outside");
+    assert_eq!(err.code, "invalid-unavailable-slot");
+}
+
+#[test]
+fn submission_parser_rejects_malformed_line() {
+    let err = parse_submission("0 1\n").expect_err("should fail");
+    assert_eq!(err.code, "invalid-placement");
+    let err = parse_submission("0 1 y\n").expect_err("should fail");
+    assert_eq!(err.code, "invalid-number");
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("0 1 0\n1 0 1\n1 3 0\n0 4 1\nx\n");
+    assert_eq!(breakdown.total_score, 5);
+    assert_eq!(breakdown.placed_servers, 4);
+    assert_eq!(breakdown.bottleneck_pool, Some(0));
+    assert_eq!(breakdown.pools[0].guaranteed_capacity, 5);
+    assert_eq!(breakdown.pools[0].worst_row, Some(0));
+    assert_eq!(breakdown.pools[1].guaranteed_capacity, 5);
+}
+
+#[test]
+fn scoring_rejects_unavailable_slot() {
+    let err = example().score_error("0 0 0\nx\nx\nx\nx\n");
+    assert_eq!(err.code, "unavailable-slot");
+}
+
+#[test]
+fn scoring_rejects_overlapping_servers() {
+    let err = example().score_error("0 1 0\n0 2 1\nx\nx\nx\n");
+    assert_eq!(err.code, "overlapping-servers");
+}
+
+#[test]
+fn scoring_rejects_server_past_row_end() {
+    let example = example();
+    let err = example.score_error("1 3 0\nx\nx\nx\nx\n");
+    assert_eq!(err.code, "server-out-of-bounds");
+
+    for slot in ["5", "18446744073709551615"] {
+        let err = example.score_error(&format!("0 {slot} 0\nx\nx\nx\nx\n"));
+        assert_eq!(err.code, "server-out-of-bounds");
+    }
+}
+
+#[test]
+fn scoring_rejects_unknown_pool_and_missing_lines() {
+    let example = example();
+    assert_eq!(
+        example.score_error("0 1 2\nx\nx\nx\nx\n").code,
+        "invalid-pool"
+    );
+    assert_eq!(example.score_error("x\n").code, "server-count-mismatch");
+}
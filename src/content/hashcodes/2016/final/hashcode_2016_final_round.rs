@@ -0,0 +1,107 @@
+//! Google Hash Code 2016 Final Round ("Satellites") implementation.
+
+#[path = "hashcode_2016_final_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2016_final_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2016_final_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2016_final_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2016 final round.
+pub struct HashCode2016Final;
+
+impl Problem for HashCode2016Final {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2016_final_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2016"
+    }
+
+    fn round(&self) -> &'static str {
+        "final"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["final_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
+
+/// Arcseconds in 90 degrees.
+pub const QUARTER_TURN: i64 = 90 * 60 * 60;
+/// Arcseconds in 180 degrees.
+pub const HALF_TURN: i64 = 2 * QUARTER_TURN;
+/// Longitude change per turn caused by the Earth's rotation.
+pub const EARTH_ROTATION: i64 = 15;
+
+/// Location on Earth as `(latitude, longitude)` in arcseconds.
+pub type Location = (i64, i64);
+
+/// Position and latitude velocity of a satellite at a given turn.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Orbit {
+    pub latitude: i64,
+    pub longitude: i64,
+    pub velocity: i64,
+}
+
+impl Orbit {
+    /// Advances the satellite by one turn, flipping it over a pole when its
+    /// latitude would leave `[-90°, 90°]`.
+    pub fn step(self) -> Self {
+        let latitude = self.latitude + self.velocity;
+        let longitude = self.longitude - EARTH_ROTATION;
+        let (latitude, longitude, velocity) = if latitude > QUARTER_TURN {
+            (HALF_TURN - latitude, longitude - HALF_TURN, -self.velocity)
+        } else if latitude < -QUARTER_TURN {
+            (-HALF_TURN - latitude, longitude - HALF_TURN, -self.velocity)
+        } else {
+            (latitude, longitude, self.velocity)
+        };
+        Orbit {
+            latitude,
+            longitude: wrap_longitude(longitude),
+            velocity,
+        }
+    }
+}
+
+/// Brings a longitude back into `[-180°, 180°)`.
+pub fn wrap_longitude(longitude: i64) -> i64 {
+    (longitude + HALF_TURN).rem_euclid(2 * HALF_TURN) - HALF_TURN
+}
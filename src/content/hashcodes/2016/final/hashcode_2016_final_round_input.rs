@@ -0,0 +1,120 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+use super::{Location, Orbit};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Satellite {
+    /// Position and velocity at turn 0.
+    pub orbit: Orbit,
+    /// Maximum orientation change per turn, in each dimension.
+    pub max_rotation_speed: i64,
+    /// Maximum absolute orientation, in each dimension.
+    pub max_rotation: i64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Collection {
+    pub value: u64,
+    pub locations: Vec<Location>,
+    /// Inclusive turn ranges in which the images may be taken.
+    pub ranges: Vec<(u64, u64)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub turns: u64,
+    pub satellites: Vec<Satellite>,
+    pub collections: Vec<Collection>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "constellation",
+        file: "constellation.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2016_final_round.in/constellation.in"),
+    },
+    Dataset {
+        name: "forever_alone",
+        file: "forever_alone.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2016_final_round.in/forever_alone.in"),
+    },
+    Dataset {
+        name: "overlap",
+        file: "overlap.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2016_final_round.in/overlap.in"),
+    },
+    Dataset {
+        name: "weekend",
+        file: "weekend.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2016_final_round.in/weekend.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2016", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let turns = tokens.parse::<u64>("turns")?;
+
+    let satellite_count = tokens.parse::<usize>("satellites")?;
+    let mut satellites = Vec::with_capacity(satellite_count);
+    for _ in 0..satellite_count {
+        let orbit = Orbit {
+            latitude: tokens.parse::<i64>("satellite_latitude")?,
+            longitude: tokens.parse::<i64>("satellite_longitude")?,
+            velocity: tokens.parse::<i64>("satellite_velocity")?,
+        };
+        satellites.push(Satellite {
+            orbit,
+            max_rotation_speed: tokens.parse::<i64>("max_rotation_speed")?,
+            max_rotation: tokens.parse::<i64>("max_rotation")?,
+        });
+    }
+
+    let collection_count = tokens.parse::<usize>("collections")?;
+    let mut collections = Vec::with_capacity(collection_count);
+    for index in 0..collection_count {
+        let value = tokens.parse::<u64>("collection_value")?;
+        let location_count = tokens.parse::<usize>("collection_locations")?;
+        let range_count = tokens.parse::<usize>("collection_ranges")?;
+        let mut locations = Vec::with_capacity(location_count);
+        for _ in 0..location_count {
+            let latitude = tokens.parse::<i64>("location_latitude")?;
+            let longitude = tokens.parse::<i64>("location_longitude")?;
+            locations.push((latitude, longitude));
+        }
+        let mut ranges = Vec::with_capacity(range_count);
+        for _ in 0..range_count {
+            let start = tokens.parse::<u64>("range_start")?;
+            let end = tokens.parse::<u64>("range_end")?;
+            if start > end || end >= turns {
+                return Err(ProblemError::with_details(
+                    "invalid-time-range",
+                    format!("Collection {index} has an invalid time range {start}-{end}"),
+                    serde_json::json!({ "collection": index, "start": start, "end": end }),
+                ));
+            }
+            ranges.push((start, end));
+        }
+        collections.push(Collection {
+            value,
+            locations,
+            ranges,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        turns,
+        satellites,
+        collections,
+    })
+}
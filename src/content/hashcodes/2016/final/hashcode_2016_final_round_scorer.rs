@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+use super::{wrap_longitude, Location};
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    /// Indices of the collections whose value was awarded.
+    pub completed_collections: Vec<usize>,
+    /// Indices of photos that do not count towards any completed collection.
+    pub wasted_photos: Vec<usize>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "total_score": self.total_score,
+            "completed_collection_count": self.completed_collections.len(),
+            "completed_collections": self.completed_collections,
+            "wasted_photo_count": self.wasted_photos.len(),
+            "wasted_photos": self.wasted_photos,
+        })
+    }
+}
+
+/// Validates the photos and awards collections as described in the statement:
+/// - there are at most as many photos as location entries in all collections
+/// - a satellite takes at most one photo per turn, within the simulation
+/// - the photographed location must be within `d` arcseconds of the
+///   satellite in both dimensions, where the satellite position is
+///   propagated turn by turn from turn 0, flipping over the poles
+/// - starting from a neutral camera at turn 0, the camera orientation cannot
+///   change faster than `w` arcseconds per turn in either dimension
+///
+/// A collection scores its value when every location was photographed in one
+/// of its time ranges. One photo may count for several collections.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let max_photos: usize = input
+        .collections
+        .iter()
+        .map(|collection| collection.locations.len())
+        .sum();
+    if submission.photos.len() > max_photos {
+        return Err(ProblemError::with_details(
+            "too-many-photos",
+            format!(
+                "Submission takes {actual} photos but at most {max_photos} are allowed",
+                actual = submission.photos.len()
+            ),
+            serde_json::json!({ "actual": submission.photos.len(), "max": max_photos }),
+        ));
+    }
+
+    let mut per_satellite: Vec<Vec<usize>> = vec![Vec::new(); input.satellites.len()];
+    for (index, photo) in submission.photos.iter().enumerate() {
+        if photo.satellite >= input.satellites.len() {
+            return Err(ProblemError::with_details(
+                "unknown-satellite",
+                format!(
+                    "Photo {index} is taken by satellite {satellite} which does not exist",
+                    satellite = photo.satellite
+                ),
+                serde_json::json!({ "photo": index, "satellite": photo.satellite }),
+            ));
+        }
+        if photo.turn >= input.turns {
+            return Err(ProblemError::with_details(
+                "invalid-turn",
+                format!(
+                    "Photo {index} is taken in turn {turn} but the simulation has {turns} turns",
+                    turn = photo.turn,
+                    turns = input.turns
+                ),
+                serde_json::json!({ "photo": index, "turn": photo.turn, "turns": input.turns }),
+            ));
+        }
+        per_satellite[photo.satellite].push(index);
+    }
+
+    for (satellite_index, photos) in per_satellite.iter_mut().enumerate() {
+        photos.sort_by_key(|&index| submission.photos[index].turn);
+        validate_satellite(input, submission, satellite_index, photos)?;
+    }
+
+    // Every photo indexed by location, sorted by turn.
+    let mut by_location: HashMap<Location, Vec<(u64, usize)>> = HashMap::new();
+    for (index, photo) in submission.photos.iter().enumerate() {
+        by_location
+            .entry(photo.location)
+            .or_default()
+            .push((photo.turn, index));
+    }
+    for photos in by_location.values_mut() {
+        photos.sort_unstable();
+    }
+
+    let mut used = vec![false; submission.photos.len()];
+    let mut completed_collections = Vec::new();
+    let mut total_score = 0u64;
+    let mut matches = Vec::new();
+    for (collection_index, collection) in input.collections.iter().enumerate() {
+        matches.clear();
+        for location in &collection.locations {
+            let photos = match by_location.get(location) {
+                Some(photos) => photos,
+                None => break,
+            };
+            let found = collection.ranges.iter().find_map(|&(start, end)| {
+                let first = photos.partition_point(|&(turn, _)| turn < start);
+                photos
+                    .get(first)
+                    .filter(|&&(turn, _)| turn <= end)
+                    .map(|&(_, index)| index)
+            });
+            match found {
+                Some(index) => matches.push(index),
+                None => break,
+            }
+        }
+        if matches.len() == collection.locations.len() {
+            total_score += collection.value;
+            completed_collections.push(collection_index);
+            for &index in &matches {
+                used[index] = true;
+            }
+        }
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        completed_collections,
+        wasted_photos: (0..used.len()).filter(|&index| !used[index]).collect(),
+    })
+}
+
+/// Replays one satellite's orbit and checks its photos in turn order.
+fn validate_satellite(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+    satellite_index: usize,
+    photos: &[usize],
+) -> Result<(), ProblemError> {
+    let satellite = &input.satellites[satellite_index];
+    let mut orbit = satellite.orbit;
+    let mut turn = 0u64;
+    let mut previous: Option<(usize, u64, (i64, i64))> = None;
+
+    for &index in photos {
+        let photo = &submission.photos[index];
+        while turn < photo.turn {
+            orbit = orbit.step();
+            turn += 1;
+        }
+
+        let orientation = (
+            photo.location.0 - orbit.latitude,
+            wrap_longitude(photo.location.1 - orbit.longitude),
+        );
+        if orientation.0.abs() > satellite.max_rotation
+            || orientation.1.abs() > satellite.max_rotation
+        {
+            return Err(ProblemError::with_details(
+                "location-out-of-range",
+                format!(
+                    "Photo {index}: satellite {satellite_index} cannot see the location in turn {turn}"
+                ),
+                serde_json::json!({
+                    "photo": index,
+                    "satellite": satellite_index,
+                    "turn": turn,
+                    "satellite_position": [orbit.latitude, orbit.longitude],
+                    "location": [photo.location.0, photo.location.1],
+                    "max_rotation": satellite.max_rotation,
+                }),
+            ));
+        }
+
+        let (previous_index, previous_turn, previous_orientation) =
+            previous.unwrap_or((index, 0, (0, 0)));
+        if previous.is_some() && previous_turn == turn {
+            return Err(ProblemError::with_details(
+                "duplicate-photo-turn",
+                format!(
+                    "Satellite {satellite_index} takes photos {previous_index} and {index} in the same turn {turn}"
+                ),
+                serde_json::json!({
+                    "satellite": satellite_index,
+                    "photos": [previous_index, index],
+                    "turn": turn,
+                }),
+            ));
+        }
+        let budget = satellite.max_rotation_speed * (turn - previous_turn) as i64;
+        if (orientation.0 - previous_orientation.0).abs() > budget
+            || (orientation.1 - previous_orientation.1).abs() > budget
+        {
+            return Err(ProblemError::with_details(
+                "camera-too-fast",
+                format!(
+                    "Photo {index}: satellite {satellite_index} cannot rotate its camera fast enough"
+                ),
+                serde_json::json!({
+                    "photo": index,
+                    "satellite": satellite_index,
+                    "turn": turn,
+                    "previous_turn": previous_turn,
+                    "orientation": [orientation.0, orientation.1],
+                    "previous_orientation": [previous_orientation.0, previous_orientation.1],
+                    "max_rotation_speed": satellite.max_rotation_speed,
+                }),
+            ));
+        }
+        previous = Some((index, turn, orientation));
+    }
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+use super::Location;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Photo {
+    pub location: Location,
+    pub turn: u64,
+    pub satellite: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub photos: Vec<Photo>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-photo-count",
+            "Submission must start with the number of photos",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-photo-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+
+    let mut photos = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        let latitude = tokens.parse::<i64>("latitude")?;
+        let longitude = tokens.parse::<i64>("longitude")?;
+        let turn = tokens.parse::<u64>("turn")?;
+        let satellite = tokens.parse::<usize>("satellite")?;
+        photos.push(Photo {
+            location: (latitude, longitude),
+            turn,
+            satellite,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { photos })
+}
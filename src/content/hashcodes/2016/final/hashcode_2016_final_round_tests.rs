@@ -0,0 +1,118 @@
+use super::HashCode2016Final;
+use super::Orbit;
+use crate::hashcodes::test_support::Fixture;
+
+// The example from the statement: one hour, two satellites over Paris and
+// three collections.
+const EXAMPLE: &str = "3600
+2
+170000 8300 300 50 500
+180000 8300 -300 50 500
+3
+100 1 1
+175958 8387
+0 3599
+100 1 2
+175889 8260
+0 900
+2700 3599
+300 2 1
+175958 8387
+175889 8260
+3300 3599
+";
+
+fn example() -> Fixture<HashCode2016Final> {
+    Fixture::inline(HashCode2016Final, EXAMPLE)
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2016Final, "forever_alone").input;
+    assert_eq!(parsed.turns, 604800);
+    assert_eq!(parsed.satellites.len(), 1);
+    assert_eq!(parsed.collections.len(), 1024);
+}
+
+#[test]
+fn orbit_flips_over_the_north_pole() {
+    let mut orbit = Orbit {
+        latitude: 176400,
+        longitude: 7200,
+        velocity: 120,
+    };
+    orbit = orbit.step();
+    assert_eq!((orbit.latitude, orbit.longitude), (176520, 7185));
+    for _ in 1..3600 {
+        orbit = orbit.step();
+    }
+    assert_eq!(orbit.latitude, 39600);
+    assert_eq!(orbit.velocity, -120);
+}
+
+#[test]
+fn orbit_flips_over_the_south_pole() {
+    let orbit = Orbit {
+        latitude: -323950,
+        longitude: -647990,
+        velocity: -100,
+    }
+    .step();
+    assert_eq!(orbit.latitude, -323950);
+    assert_eq!(orbit.velocity, 100);
+    assert_eq!(orbit.longitude, -5);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("2\n175958 8387 12 1\n175889 8260 18 0\n");
+    assert_eq!(breakdown.total_score, 200);
+    assert_eq!(breakdown.completed_collections, vec![0, 1]);
+    assert!(breakdown.wasted_photos.is_empty());
+}
+
+#[test]
+fn scoring_reports_wasted_photos() {
+    let breakdown = example().score("2\n175958 8387 12 1\n175958 8387 20 0\n");
+    assert_eq!(breakdown.total_score, 100);
+    assert_eq!(breakdown.wasted_photos, vec![1]);
+}
+
+#[test]
+fn scoring_rejects_location_out_of_range() {
+    let err = example().score_error("1\n175958 8387 1000 1\n");
+    assert_eq!(err.code, "location-out-of-range");
+}
+
+#[test]
+fn scoring_rejects_fast_camera_moves() {
+    // Satellite 1 moves 300 arcseconds of latitude per turn while its camera
+    // only rotates 50 arcseconds per turn.
+    let err = example().score_error("2\n175958 8387 12 1\n175958 8387 13 1\n");
+    assert_eq!(err.code, "camera-too-fast");
+}
+
+#[test]
+fn scoring_rejects_invalid_photos() {
+    let example = example();
+    assert_eq!(
+        example.score_error("1\n175958 8387 12 2\n").code,
+        "unknown-satellite"
+    );
+    assert_eq!(
+        example.score_error("1\n175958 8387 3600 1\n").code,
+        "invalid-turn"
+    );
+    assert_eq!(
+        example
+            .score_error("2\n175958 8387 12 1\n175958 8387 12 1\n")
+            .code,
+        "duplicate-photo-turn"
+    );
+    assert_eq!(
+        example
+            .score_error("5\n0 0 1 0\n0 0 2 0\n0 0 3 0\n0 0 4 0\n0 0 5 0\n")
+            .code,
+        "too-many-photos"
+    );
+}
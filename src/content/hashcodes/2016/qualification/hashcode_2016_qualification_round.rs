@@ -0,0 +1,98 @@
+//! Google Hash Code 2016 Qualification Round ("Delivery") implementation.
+
+#[path = "hashcode_2016_qualification_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2016_qualification_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2016_qualification_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2016_qualification_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2016 qualification round.
+pub struct HashCode2016Qualification;
+
+impl Problem for HashCode2016Qualification {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2016_qualification_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2016"
+    }
+
+    fn round(&self) -> &'static str {
+        "qualification"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["qualification_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
+
+/// Grid cell as `(row, column)`.
+pub type Cell = (i64, i64);
+
+/// Number of turns a drone needs to fly between two cells.
+pub fn flight_turns(from: Cell, to: Cell) -> u64 {
+    let delta_row = (from.0 - to.0) as f64;
+    let delta_col = (from.1 - to.1) as f64;
+    (delta_row * delta_row + delta_col * delta_col)
+        .sqrt()
+        .ceil() as u64
+}
+
+/// A single drone command from the submission.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Command {
+    Load {
+        warehouse: usize,
+        product: usize,
+        quantity: u32,
+    },
+    Unload {
+        warehouse: usize,
+        product: usize,
+        quantity: u32,
+    },
+    Deliver {
+        order: usize,
+        product: usize,
+        quantity: u32,
+    },
+    Wait {
+        turns: u32,
+    },
+}
@@ -0,0 +1,139 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+use super::Cell;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Warehouse {
+    pub cell: Cell,
+    /// Items in stock for every product type.
+    pub stock: Vec<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Order {
+    pub cell: Cell,
+    /// Product type of every ordered item.
+    pub items: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub rows: i64,
+    pub cols: i64,
+    pub drones: usize,
+    pub deadline: u64,
+    pub max_load: u64,
+    pub product_weights: Vec<u64>,
+    pub warehouses: Vec<Warehouse>,
+    pub orders: Vec<Order>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "busy_day",
+        file: "busy_day.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2016_qualification_round.in/busy_day.in"),
+    },
+    Dataset {
+        name: "mother_of_all_warehouses",
+        file: "mother_of_all_warehouses.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2016_qualification_round.in/mother_of_all_warehouses.in"),
+    },
+    Dataset {
+        name: "redundancy",
+        file: "redundancy.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2016_qualification_round.in/redundancy.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2016", "qualification", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let rows = tokens.parse::<i64>("rows")?;
+    let cols = tokens.parse::<i64>("columns")?;
+    let drones = tokens.parse::<usize>("drones")?;
+    let deadline = tokens.parse::<u64>("deadline")?;
+    let max_load = tokens.parse::<u64>("max_load")?;
+
+    let product_count = tokens.parse::<usize>("product_types")?;
+    let mut product_weights = Vec::with_capacity(product_count);
+    for _ in 0..product_count {
+        product_weights.push(tokens.parse::<u64>("product_weight")?);
+    }
+
+    let warehouse_count = tokens.parse::<usize>("warehouses")?;
+    let mut warehouses = Vec::with_capacity(warehouse_count);
+    for index in 0..warehouse_count {
+        let cell = parse_cell(&mut tokens, rows, cols, "warehouse", index)?;
+        let mut stock = Vec::with_capacity(product_count);
+        for _ in 0..product_count {
+            stock.push(tokens.parse::<u32>("warehouse_stock")?);
+        }
+        warehouses.push(Warehouse { cell, stock });
+    }
+    if warehouses.is_empty() {
+        return Err(ProblemError::new(
+            "missing-warehouse",
+            "Input must describe at least one warehouse",
+        ));
+    }
+
+    let order_count = tokens.parse::<usize>("orders")?;
+    let mut orders = Vec::with_capacity(order_count);
+    for index in 0..order_count {
+        let cell = parse_cell(&mut tokens, rows, cols, "order", index)?;
+        let item_count = tokens.parse::<usize>("order_items")?;
+        let mut items = Vec::with_capacity(item_count);
+        for _ in 0..item_count {
+            let product = tokens.parse::<usize>("order_product")?;
+            if product >= product_count {
+                return Err(ProblemError::with_details(
+                    "invalid-product",
+                    format!("Order {index} references unknown product type {product}"),
+                    serde_json::json!({ "order": index, "product": product }),
+                ));
+            }
+            items.push(product);
+        }
+        orders.push(Order { cell, items });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        rows,
+        cols,
+        drones,
+        deadline,
+        max_load,
+        product_weights,
+        warehouses,
+        orders,
+    })
+}
+
+fn parse_cell(
+    tokens: &mut Tokens<'_>,
+    rows: i64,
+    cols: i64,
+    kind: &str,
+    index: usize,
+) -> Result<Cell, ProblemError> {
+    let row = tokens.parse::<i64>("row")?;
+    let col = tokens.parse::<i64>("column")?;
+    if !(0..rows).contains(&row) || !(0..cols).contains(&col) {
+        return Err(ProblemError::with_details(
+            "invalid-cell",
+            format!("The {kind} {index} lies outside the grid"),
+            serde_json::json!({ "kind": kind, "index": index, "row": row, "col": col }),
+        ));
+    }
+    Ok((row, col))
+}
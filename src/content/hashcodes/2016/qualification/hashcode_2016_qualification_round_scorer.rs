@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::{DroneCommand, ProblemSubmission};
+use super::{flight_turns, Command};
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub completed_orders: usize,
+    /// Turn in which each order was completed, if it was.
+    pub order_completion: Vec<Option<u64>>,
+    /// First turn in which each drone has no command left.
+    pub drone_finish_turns: Vec<u64>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "total_score": self.total_score,
+            "completed_orders": self.completed_orders,
+            "order_completion": self.order_completion,
+            "drone_finish_turns": self.drone_finish_turns,
+        })
+    }
+}
+
+/// Location of a command within the submission, used in error payloads.
+#[derive(Clone, Copy)]
+struct Origin {
+    drone: usize,
+    /// Index of the command among the commands of its drone.
+    command: usize,
+    line: usize,
+    turn: u64,
+}
+
+impl Origin {
+    fn error(
+        self,
+        code: &'static str,
+        message: impl Into<String>,
+        mut details: serde_json::Value,
+    ) -> ProblemError {
+        if let Some(map) = details.as_object_mut() {
+            map.insert("drone".into(), self.drone.into());
+            map.insert("command".into(), self.command.into());
+            map.insert("line".into(), self.line.into());
+            map.insert("turn".into(), self.turn.into());
+        }
+        ProblemError::with_details(
+            code,
+            format!(
+                "Drone {drone}, command {command}: {message}",
+                drone = self.drone,
+                command = self.command,
+                message = message.into()
+            ),
+            details,
+        )
+    }
+}
+
+/// Warehouse stock change, applied in turn order once every drone is simulated.
+struct StockEvent {
+    origin: Origin,
+    unload: bool,
+    warehouse: usize,
+    product: usize,
+    quantity: u32,
+}
+
+struct DeliveryEvent {
+    origin: Origin,
+    order: usize,
+    product: usize,
+    quantity: u32,
+}
+
+struct Drone {
+    cell: (i64, i64),
+    next_turn: u64,
+    commands: usize,
+    inventory: HashMap<usize, u32>,
+    load: u64,
+}
+
+/// Simulates the drone commands as described in the statement:
+/// - every drone starts at warehouse 0 and runs its commands in order
+/// - `L`, `U` and `D` first fly to the target, rounding the Euclidean
+///   distance up, and then spend one turn on the action itself
+/// - a drone never carries more than the maximum load and only unloads or
+///   delivers items it carries
+/// - warehouses cannot go below zero stock; within one turn every unload is
+///   processed before any load
+/// - no order receives more items of a product than it asked for, and every
+///   drone finishes within `T` turns
+///
+/// An order completed in turn `t` earns `ceil((T - t) / T * 100)` points.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let start = input.warehouses[0].cell;
+    let mut drones: Vec<Drone> = (0..input.drones)
+        .map(|_| Drone {
+            cell: start,
+            next_turn: 0,
+            commands: 0,
+            inventory: HashMap::new(),
+            load: 0,
+        })
+        .collect();
+    let mut stock_events = Vec::new();
+    let mut delivery_events = Vec::new();
+
+    for entry in &submission.commands {
+        let drone = drones.get_mut(entry.drone).ok_or_else(|| {
+            ProblemError::with_details(
+                "unknown-drone",
+                format!(
+                    "Line {line} commands drone {drone} but only {count} drones exist",
+                    line = entry.line,
+                    drone = entry.drone,
+                    count = input.drones
+                ),
+                serde_json::json!({ "line": entry.line, "drone": entry.drone }),
+            )
+        })?;
+        simulate_command(input, drone, entry, &mut stock_events, &mut delivery_events)?;
+    }
+
+    stock_events.sort_by_key(|event| {
+        (
+            event.origin.turn,
+            !event.unload,
+            event.origin.drone,
+            event.origin.command,
+        )
+    });
+    let mut stock: Vec<Vec<u32>> = input
+        .warehouses
+        .iter()
+        .map(|warehouse| warehouse.stock.clone())
+        .collect();
+    for event in &stock_events {
+        let available = &mut stock[event.warehouse][event.product];
+        if event.unload {
+            *available += event.quantity;
+        } else if *available < event.quantity {
+            return Err(event.origin.error(
+                "not-enough-stock",
+                format!(
+                    "warehouse {warehouse} only has {available} items of product {product} left",
+                    warehouse = event.warehouse,
+                    product = event.product
+                ),
+                serde_json::json!({
+                    "warehouse": event.warehouse,
+                    "product": event.product,
+                    "requested": event.quantity,
+                    "available": *available,
+                }),
+            ));
+        } else {
+            *available -= event.quantity;
+        }
+    }
+
+    let mut missing: Vec<HashMap<usize, u32>> = input
+        .orders
+        .iter()
+        .map(|order| {
+            let mut needed = HashMap::new();
+            for &product in &order.items {
+                *needed.entry(product).or_insert(0) += 1;
+            }
+            needed
+        })
+        .collect();
+    let mut missing_totals: Vec<usize> =
+        input.orders.iter().map(|order| order.items.len()).collect();
+    let mut order_completion = vec![None; input.orders.len()];
+
+    delivery_events
+        .sort_by_key(|event| (event.origin.turn, event.origin.drone, event.origin.command));
+    for event in &delivery_events {
+        let needed = missing[event.order]
+            .get(&event.product)
+            .copied()
+            .unwrap_or(0);
+        if needed < event.quantity {
+            return Err(event.origin.error(
+                "order-overfilled",
+                format!(
+                    "order {order} only needs {needed} more items of product {product}",
+                    order = event.order,
+                    product = event.product
+                ),
+                serde_json::json!({
+                    "order": event.order,
+                    "product": event.product,
+                    "delivered": event.quantity,
+                    "needed": needed,
+                }),
+            ));
+        }
+        missing[event.order].insert(event.product, needed - event.quantity);
+        missing_totals[event.order] -= event.quantity as usize;
+        if missing_totals[event.order] == 0 {
+            order_completion[event.order] = Some(event.origin.turn);
+        }
+    }
+
+    let deadline = input.deadline;
+    let total_score = order_completion
+        .iter()
+        .flatten()
+        .map(|&turn| ((deadline - turn) * 100).div_ceil(deadline))
+        .sum();
+
+    Ok(ScoreBreakdown {
+        total_score,
+        completed_orders: order_completion.iter().flatten().count(),
+        order_completion,
+        drone_finish_turns: drones.iter().map(|drone| drone.next_turn).collect(),
+    })
+}
+
+fn simulate_command(
+    input: &ProblemInput,
+    drone: &mut Drone,
+    entry: &DroneCommand,
+    stock_events: &mut Vec<StockEvent>,
+    delivery_events: &mut Vec<DeliveryEvent>,
+) -> Result<(), ProblemError> {
+    let mut origin = Origin {
+        drone: entry.drone,
+        command: drone.commands,
+        line: entry.line,
+        turn: drone.next_turn,
+    };
+    drone.commands += 1;
+
+    let check_product = |origin: Origin, product: usize| {
+        if product >= input.product_weights.len() {
+            return Err(origin.error(
+                "unknown-product",
+                format!("product type {product} does not exist"),
+                serde_json::json!({ "product": product }),
+            ));
+        }
+        Ok(input.product_weights[product])
+    };
+    let check_warehouse = |origin: Origin, warehouse: usize| {
+        input.warehouses.get(warehouse).ok_or_else(|| {
+            origin.error(
+                "unknown-warehouse",
+                format!("warehouse {warehouse} does not exist"),
+                serde_json::json!({ "warehouse": warehouse }),
+            )
+        })
+    };
+
+    match entry.command {
+        Command::Load {
+            warehouse,
+            product,
+            quantity,
+        } => {
+            let weight = check_product(origin, product)?;
+            let cell = check_warehouse(origin, warehouse)?.cell;
+            origin.turn = drone.next_turn + flight_turns(drone.cell, cell);
+            drone.cell = cell;
+            drone.next_turn = origin.turn + 1;
+            drone.load += weight * u64::from(quantity);
+            if drone.load > input.max_load {
+                return Err(origin.error(
+                    "overweight",
+                    format!(
+                        "payload of {load} exceeds the maximum load of {max}",
+                        load = drone.load,
+                        max = input.max_load
+                    ),
+                    serde_json::json!({ "load": drone.load, "max_load": input.max_load }),
+                ));
+            }
+            *drone.inventory.entry(product).or_insert(0) += quantity;
+            stock_events.push(StockEvent {
+                origin,
+                unload: false,
+                warehouse,
+                product,
+                quantity,
+            });
+        }
+        Command::Unload {
+            warehouse,
+            product,
+            quantity,
+        } => {
+            let weight = check_product(origin, product)?;
+            let cell = check_warehouse(origin, warehouse)?.cell;
+            origin.turn = drone.next_turn + flight_turns(drone.cell, cell);
+            drone.cell = cell;
+            drone.next_turn = origin.turn + 1;
+            take_from_inventory(drone, origin, product, quantity, weight)?;
+            stock_events.push(StockEvent {
+                origin,
+                unload: true,
+                warehouse,
+                product,
+                quantity,
+            });
+        }
+        Command::Deliver {
+            order,
+            product,
+            quantity,
+        } => {
+            let weight = check_product(origin, product)?;
+            let cell = input
+                .orders
+                .get(order)
+                .ok_or_else(|| {
+                    origin.error(
+                        "unknown-order",
+                        format!("order {order} does not exist"),
+                        serde_json::json!({ "order": order }),
+                    )
+                })?
+                .cell;
+            origin.turn = drone.next_turn + flight_turns(drone.cell, cell);
+            drone.cell = cell;
+            drone.next_turn = origin.turn + 1;
+            take_from_inventory(drone, origin, product, quantity, weight)?;
+            delivery_events.push(DeliveryEvent {
+                origin,
+                order,
+                product,
+                quantity,
+            });
+        }
+        Command::Wait { turns } => {
+            drone.next_turn += u64::from(turns);
+        }
+    }
+
+    if drone.next_turn > input.deadline {
+        return Err(origin.error(
+            "time-limit-exceeded",
+            format!(
+                "commands run until turn {end} but the simulation ends after {deadline} turns",
+                end = drone.next_turn,
+                deadline = input.deadline
+            ),
+            serde_json::json!({ "end_turn": drone.next_turn, "deadline": input.deadline }),
+        ));
+    }
+    Ok(())
+}
+
+fn take_from_inventory(
+    drone: &mut Drone,
+    origin: Origin,
+    product: usize,
+    quantity: u32,
+    weight: u64,
+) -> Result<(), ProblemError> {
+    let carried = drone.inventory.get(&product).copied().unwrap_or(0);
+    if carried < quantity {
+        return Err(origin.error(
+            "not-enough-items",
+            format!("drone carries {carried} items of product {product} but needs {quantity}"),
+            serde_json::json!({ "product": product, "carried": carried, "requested": quantity }),
+        ));
+    }
+    drone.inventory.insert(product, carried - quantity);
+    drone.load -= weight * u64::from(quantity);
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use crate::hashcodes::error::ProblemError;
+
+use super::Command;
+
+/// A command together with the drone it is issued to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DroneCommand {
+    pub drone: usize,
+    pub command: Command,
+    /// 1-based line of the command in the submission.
+    pub line: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub commands: Vec<DroneCommand>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut lines = raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or_else(|| {
+        ProblemError::new(
+            "missing-command-count",
+            "Submission must start with the number of commands",
+        )
+    })?;
+    let declared_count = header.trim().parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-command-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": header.trim() }),
+        )
+    })?;
+
+    let mut commands = Vec::with_capacity(declared_count.min(1 << 20));
+    for (index, line) in lines {
+        commands.push(parse_command(line, index + 1)?);
+    }
+
+    if commands.len() != declared_count {
+        return Err(ProblemError::with_details(
+            "command-count-mismatch",
+            format!(
+                "Submission declares {declared_count} commands but provided {actual}",
+                actual = commands.len()
+            ),
+            serde_json::json!({ "declared": declared_count, "actual": commands.len() }),
+        ));
+    }
+
+    Ok(ProblemSubmission { commands })
+}
+
+fn parse_command(line: &str, line_no: usize) -> Result<DroneCommand, ProblemError> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let invalid = || {
+        ProblemError::with_details(
+            "invalid-command",
+            format!("Line {line_no} is not a valid L, U, D or W command"),
+            serde_json::json!({ "line": line_no, "content": line }),
+        )
+    };
+    let number = |position: usize| -> Result<usize, ProblemError> {
+        let value = parts[position];
+        value.parse::<usize>().map_err(|_err| {
+            ProblemError::with_details(
+                "invalid-number",
+                format!("Value '{value}' on line {line_no} is not a non-negative integer"),
+                serde_json::json!({ "line": line_no, "position": position, "value": value }),
+            )
+        })
+    };
+    let positive = |position: usize| -> Result<u32, ProblemError> {
+        let value = number(position)?;
+        if value == 0 || value > u32::MAX as usize {
+            return Err(ProblemError::with_details(
+                "invalid-quantity",
+                format!("Line {line_no} must use a positive number of items or turns"),
+                serde_json::json!({ "line": line_no, "value": value }),
+            ));
+        }
+        Ok(value as u32)
+    };
+
+    let command = match parts.as_slice() {
+        [_, "L", _, _, _] => Command::Load {
+            warehouse: number(2)?,
+            product: number(3)?,
+            quantity: positive(4)?,
+        },
+        [_, "U", _, _, _] => Command::Unload {
+            warehouse: number(2)?,
+            product: number(3)?,
+            quantity: positive(4)?,
+        },
+        [_, "D", _, _, _] => Command::Deliver {
+            order: number(2)?,
+            product: number(3)?,
+            quantity: positive(4)?,
+        },
+        [_, "W", _] => Command::Wait {
+            turns: positive(2)?,
+        },
+        _ => return Err(invalid()),
+    };
+
+    Ok(DroneCommand {
+        drone: number(0)?,
+        command,
+        line: line_no,
+    })
+}
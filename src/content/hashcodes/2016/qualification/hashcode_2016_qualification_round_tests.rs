@@ -0,0 +1,129 @@
+use super::submission::parse_submission;
+use super::HashCode2016Qualification;
+use crate::hashcodes::test_support::Fixture;
+
+// The example from the statement: 3 drones, 50 turns and three orders.
+const EXAMPLE: &str = "100 100 3 50 500
+3
+100 5 450
+2
+0 0
+5 1 0
+5 5
+0 10 2
+3
+1 1
+2
+2 0
+3 3
+1
+0
+5 6
+1
+2
+";
+
+const EXAMPLE_SUBMISSION: &str = "9
+0 L 0 0 1
+0 L 0 1 1
+0 D 0 0 1
+0 L 1 2 1
+0 D 0 2 1
+1 L 1 2 1
+1 D 2 2 1
+1 L 0 0 1
+1 D 1 0 1
+";
+
+fn example() -> Fixture<HashCode2016Qualification> {
+    Fixture::inline(HashCode2016Qualification, EXAMPLE)
+}
+
+#[test]
+fn official_datasets_parse() {
+    for name in ["busy_day", "mother_of_all_warehouses.in", "redundancy"] {
+        let parsed = Fixture::dataset(HashCode2016Qualification, name).input;
+        assert!(!parsed.orders.is_empty());
+    }
+}
+
+#[test]
+fn submission_parser_rejects_bad_commands() {
+    let err = parse_submission("1\n0 X 1\n").expect_err("should fail");
+    assert_eq!(err.code, "invalid-command");
+    let err = parse_submission("1\n0 W 0\n").expect_err("should fail");
+    assert_eq!(err.code, "invalid-quantity");
+    let err = parse_submission("2\n0 W 1\n").expect_err("should fail");
+    assert_eq!(err.code, "command-count-mismatch");
+    for huge in ["18446744073709551615", "2147483648"] {
+        let err = parse_submission(huge).expect_err("should fail");
+        assert_eq!(err.code, "command-count-mismatch");
+    }
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 194);
+    assert_eq!(breakdown.completed_orders, 3);
+    assert_eq!(
+        breakdown.order_completion,
+        vec![Some(18), Some(25), Some(10)]
+    );
+}
+
+#[test]
+fn scoring_rejects_overweight_drone() {
+    let err = example().score_error("2\n0 L 0 0 5\n0 L 0 1 1\n");
+    assert_eq!(err.code, "overweight");
+    let details = err.details.expect("details");
+    assert_eq!(details["drone"], 0);
+    assert_eq!(details["command"], 1);
+}
+
+#[test]
+fn scoring_rejects_missing_stock() {
+    let err = example().score_error("2\n0 L 0 1 1\n1 L 0 1 1\n");
+    assert_eq!(err.code, "not-enough-stock");
+    assert_eq!(err.details.expect("details")["drone"], 1);
+}
+
+#[test]
+fn scoring_processes_unloads_before_loads() {
+    // Drone 1 unloads product 1 at warehouse 0 in the very turn drone 0
+    // loads the second item.
+    let submission = "4\n1 L 0 1 1\n1 U 0 1 1\n0 W 1\n0 L 0 1 1\n";
+    example().score(submission);
+}
+
+#[test]
+fn scoring_rejects_delivery_to_wrong_order() {
+    let err = example().score_error("2\n0 L 0 0 1\n0 D 2 0 1\n");
+    assert_eq!(err.code, "order-overfilled");
+    let details = err.details.expect("details");
+    assert_eq!(details["drone"], 0);
+    assert_eq!(details["command"], 1);
+}
+
+#[test]
+fn scoring_rejects_unknown_references_and_missing_items() {
+    assert_eq!(example().score_error("1\n3 W 1\n").code, "unknown-drone");
+    assert_eq!(
+        example().score_error("1\n0 L 2 0 1\n").code,
+        "unknown-warehouse"
+    );
+    assert_eq!(
+        example().score_error("1\n0 L 0 3 1\n").code,
+        "unknown-product"
+    );
+    assert_eq!(
+        example().score_error("1\n0 D 0 0 1\n").code,
+        "not-enough-items"
+    );
+}
+
+#[test]
+fn scoring_rejects_commands_past_deadline() {
+    let err = example().score_error("1\n0 W 51\n");
+    assert_eq!(err.code, "time-limit-exceeded");
+}
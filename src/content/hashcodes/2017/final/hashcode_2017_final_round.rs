@@ -0,0 +1,74 @@
+//! Google Hash Code 2017 Final Round ("Router placement") implementation.
+
+#[path = "hashcode_2017_final_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2017_final_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2017_final_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2017_final_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2017 final round.
+pub struct HashCode2017Final;
+
+impl Problem for HashCode2017Final {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2017_final_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2017"
+    }
+
+    fn round(&self) -> &'static str {
+        "final"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["final_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
+
+/// Row and column of a grid cell.
+pub type Cell = (usize, usize);
+
+/// Type of a building cell as given in the input grid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CellKind {
+    Wall,
+    Target,
+    Void,
+}
@@ -0,0 +1,118 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+use super::{Cell, CellKind};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub rows: usize,
+    pub columns: usize,
+    pub radius: usize,
+    pub backbone_cost: u64,
+    pub router_cost: u64,
+    pub budget: u64,
+    pub initial_backbone: Cell,
+    /// Row-major building grid.
+    pub grid: Vec<CellKind>,
+}
+
+impl ProblemInput {
+    pub fn cell(&self, (row, column): Cell) -> CellKind {
+        self.grid[row * self.columns + column]
+    }
+
+    pub fn contains(&self, (row, column): Cell) -> bool {
+        row < self.rows && column < self.columns
+    }
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "charleston_road",
+        file: "charleston_road.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2017_final_round.in/charleston_road.in"),
+    },
+    Dataset {
+        name: "rue_de_londres",
+        file: "rue_de_londres.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2017_final_round.in/rue_de_londres.in"),
+    },
+    Dataset {
+        name: "opera",
+        file: "opera.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2017_final_round.in/opera.in"),
+    },
+    Dataset {
+        name: "lets_go_higher",
+        file: "lets_go_higher.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2017_final_round.in/lets_go_higher.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2017", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let rows = tokens.parse::<usize>("rows")?;
+    let columns = tokens.parse::<usize>("columns")?;
+    let radius = tokens.parse::<usize>("radius")?;
+    let backbone_cost = tokens.parse::<u64>("backbone_cost")?;
+    let router_cost = tokens.parse::<u64>("router_cost")?;
+    let budget = tokens.parse::<u64>("budget")?;
+    let initial_backbone = (
+        tokens.parse::<usize>("backbone_row")?,
+        tokens.parse::<usize>("backbone_column")?,
+    );
+    if initial_backbone.0 >= rows || initial_backbone.1 >= columns {
+        return Err(ProblemError::with_details(
+            "invalid-backbone",
+            "Initial backbone cell lies outside the grid",
+            serde_json::json!({ "row": initial_backbone.0, "column": initial_backbone.1 }),
+        ));
+    }
+
+    let mut grid = Vec::with_capacity(rows * columns);
+    for row in 0..rows {
+        let line = tokens.token("grid_row")?;
+        if line.len() != columns {
+            return Err(ProblemError::with_details(
+                "invalid-grid",
+                format!("Grid row {row} must contain exactly {columns} cells"),
+                serde_json::json!({ "row": row, "line": tokens.line(), "length": line.len() }),
+            ));
+        }
+        for (column, byte) in line.bytes().enumerate() {
+            grid.push(match byte {
+                b'#' => CellKind::Wall,
+                b'.' => CellKind::Target,
+                b'-' => CellKind::Void,
+                _ => {
+                    return Err(ProblemError::with_details(
+                        "invalid-grid",
+                        format!("Unexpected character '{}' in the grid", byte as char),
+                        serde_json::json!({ "row": row, "column": column, "line": tokens.line() }),
+                    ))
+                }
+            });
+        }
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        rows,
+        columns,
+        radius,
+        backbone_cost,
+        router_cost,
+        budget,
+        initial_backbone,
+        grid,
+    })
+}
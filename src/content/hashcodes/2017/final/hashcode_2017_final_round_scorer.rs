@@ -0,0 +1,201 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+use super::{Cell, CellKind};
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub covered_targets: u64,
+    pub backbone_cost: u64,
+    pub router_cost: u64,
+    pub remaining_budget: u64,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "total_score": self.total_score,
+            "covered_targets": self.covered_targets,
+            "backbone_cost": self.backbone_cost,
+            "router_cost": self.router_cost,
+            "remaining_budget": self.remaining_budget,
+        })
+    }
+}
+
+/// Validates the backbone and routers and computes the official score:
+/// - every backbone cell lies in the grid, is listed once and neighbours
+///   (8-connected) the initial backbone cell or an earlier listed cell
+/// - every router is placed once, on a backbone cell that is not a wall
+/// - backbone cells and routers together stay within the budget
+///
+/// A router covers the cells at most `R` rows and columns away unless a wall
+/// lies in the rectangle spanned by the router and the cell. The score is
+/// 1000 points per covered target cell plus one point per unit of unspent
+/// budget.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let columns = input.columns;
+    let mut connected = vec![false; input.rows * columns];
+    connected[input.initial_backbone.0 * columns + input.initial_backbone.1] = true;
+
+    for (index, &cell) in submission.backbone.iter().enumerate() {
+        check_in_grid(input, "backbone cell", index, cell)?;
+        let (row, column) = cell;
+        if connected[row * columns + column] {
+            return Err(ProblemError::with_details(
+                "duplicate-backbone-cell",
+                format!("Backbone cell [{row}, {column}] is already connected"),
+                serde_json::json!({ "index": index, "row": row, "column": column }),
+            ));
+        }
+        let has_neighbour = (row.saturating_sub(1)..=(row + 1).min(input.rows - 1)).any(|r| {
+            (column.saturating_sub(1)..=(column + 1).min(columns - 1))
+                .any(|c| connected[r * columns + c])
+        });
+        if !has_neighbour {
+            return Err(ProblemError::with_details(
+                "disconnected-backbone-cell",
+                format!(
+                    "Backbone cell [{row}, {column}] does not neighbour the initial backbone cell \
+                     or an earlier backbone cell"
+                ),
+                serde_json::json!({ "index": index, "row": row, "column": column }),
+            ));
+        }
+        connected[row * columns + column] = true;
+    }
+
+    let mut has_router = vec![false; input.rows * columns];
+    for (index, &cell) in submission.routers.iter().enumerate() {
+        check_in_grid(input, "router", index, cell)?;
+        let (row, column) = cell;
+        let details = serde_json::json!({ "index": index, "row": row, "column": column });
+        if has_router[row * columns + column] {
+            return Err(ProblemError::with_details(
+                "duplicate-router",
+                format!("More than one router is placed at [{row}, {column}]"),
+                details,
+            ));
+        }
+        if input.cell(cell) == CellKind::Wall {
+            return Err(ProblemError::with_details(
+                "router-on-wall",
+                format!("Router at [{row}, {column}] is placed in a wall cell"),
+                details,
+            ));
+        }
+        if !connected[row * columns + column] {
+            return Err(ProblemError::with_details(
+                "router-not-on-backbone",
+                format!("Router at [{row}, {column}] is not connected to the backbone"),
+                details,
+            ));
+        }
+        has_router[row * columns + column] = true;
+    }
+
+    let backbone_cost = submission.backbone.len() as u64 * input.backbone_cost;
+    let router_cost = submission.routers.len() as u64 * input.router_cost;
+    let spent = backbone_cost + router_cost;
+    if spent > input.budget {
+        return Err(ProblemError::with_details(
+            "budget-exceeded",
+            format!(
+                "Backbone and routers cost {spent} but the budget is {budget}",
+                budget = input.budget
+            ),
+            serde_json::json!({
+                "backbone_cost": backbone_cost,
+                "router_cost": router_cost,
+                "budget": input.budget,
+            }),
+        ));
+    }
+
+    let covered_targets = count_covered_targets(input, &submission.routers);
+    let remaining_budget = input.budget - spent;
+
+    Ok(ScoreBreakdown {
+        total_score: 1000 * covered_targets + remaining_budget,
+        covered_targets,
+        backbone_cost,
+        router_cost,
+        remaining_budget,
+    })
+}
+
+fn check_in_grid(
+    input: &ProblemInput,
+    what: &str,
+    index: usize,
+    (row, column): Cell,
+) -> Result<(), ProblemError> {
+    if input.contains((row, column)) {
+        return Ok(());
+    }
+    Err(ProblemError::with_details(
+        "cell-out-of-bounds",
+        format!(
+            "The {what} at [{row}, {column}] lies outside the {rows}x{columns} grid",
+            rows = input.rows,
+            columns = input.columns
+        ),
+        serde_json::json!({ "index": index, "row": row, "column": column }),
+    ))
+}
+
+/// Counts the distinct target cells covered by at least one router, using a
+/// prefix sum of walls so each candidate cell is checked in constant time.
+fn count_covered_targets(input: &ProblemInput, routers: &[Cell]) -> u64 {
+    let (rows, columns) = (input.rows, input.columns);
+    let stride = columns + 1;
+    let mut walls = vec![0u32; (rows + 1) * stride];
+    for row in 0..rows {
+        for column in 0..columns {
+            let wall = u32::from(input.cell((row, column)) == CellKind::Wall);
+            walls[(row + 1) * stride + column + 1] =
+                wall + walls[row * stride + column + 1] + walls[(row + 1) * stride + column]
+                    - walls[row * stride + column];
+        }
+    }
+    // Walls inside the inclusive rectangle spanned by two cells.
+    let walls_between = |(a, b): Cell, (x, y): Cell| {
+        let (top, bottom) = (a.min(x), a.max(x) + 1);
+        let (left, right) = (b.min(y), b.max(y) + 1);
+        walls[bottom * stride + right] + walls[top * stride + left]
+            - walls[top * stride + right]
+            - walls[bottom * stride + left]
+    };
+
+    let mut covered = vec![false; rows * columns];
+    let mut count = 0;
+    for &router in routers {
+        let (row, column) = router;
+        for x in row.saturating_sub(input.radius)..=(row + input.radius).min(rows - 1) {
+            for y in column.saturating_sub(input.radius)..=(column + input.radius).min(columns - 1)
+            {
+                let index = x * columns + y;
+                if covered[index]
+                    || input.grid[index] != CellKind::Target
+                    || walls_between(router, (x, y)) != 0
+                {
+                    continue;
+                }
+                covered[index] = true;
+                count += 1;
+            }
+        }
+    }
+    count
+}
@@ -0,0 +1,45 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+use super::Cell;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Backbone cells in connection order, excluding the initial cell.
+    pub backbone: Vec<Cell>,
+    pub routers: Vec<Cell>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-backbone-count",
+            "Submission must start with the number of backbone cells",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-backbone-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+    let backbone = parse_cells(&mut tokens, count)?;
+
+    let count = tokens.parse::<usize>("router_count")?;
+    let routers = parse_cells(&mut tokens, count)?;
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { backbone, routers })
+}
+
+fn parse_cells(tokens: &mut Tokens<'_>, count: usize) -> Result<Vec<Cell>, ProblemError> {
+    let mut cells = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        let row = tokens.parse::<usize>("row")?;
+        let column = tokens.parse::<usize>("column")?;
+        cells.push((row, column));
+    }
+    Ok(cells)
+}
@@ -0,0 +1,109 @@
+use super::submission::parse_submission;
+use super::CellKind;
+use super::HashCode2017Final;
+use crate::hashcodes::test_support::Fixture;
+
+// The example from the statement: an 8x22 building, router radius 3,
+// backbone cost 1, router cost 100 and a budget of 220.
+const EXAMPLE: &str = "8 22 3
+1 100 220
+2 7
+----------------------
+-########----########-
+-#......######......#-
+-#..................#-
+-#..................#-
+-#..................#-
+-####################-
+----------------------
+";
+
+const EXAMPLE_SUBMISSION: &str = "3\n3 6\n3 8\n3 9\n2\n3 6\n3 9\n";
+
+fn example() -> Fixture<HashCode2017Final> {
+    Fixture::inline(HashCode2017Final, EXAMPLE)
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2017Final, "charleston_road").input;
+    assert_eq!((parsed.rows, parsed.columns, parsed.radius), (240, 180, 10));
+    assert_eq!(parsed.initial_backbone, (120, 90));
+    assert_eq!(parsed.grid.len(), 240 * 180);
+}
+
+#[test]
+fn input_parser_reads_the_grid() {
+    let input = example().input;
+    assert_eq!(input.cell((0, 0)), CellKind::Void);
+    assert_eq!(input.cell((1, 1)), CellKind::Wall);
+    assert_eq!(input.cell((2, 2)), CellKind::Target);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 35017);
+    assert_eq!(breakdown.covered_targets, 35);
+    assert_eq!(breakdown.backbone_cost, 3);
+    assert_eq!(breakdown.router_cost, 200);
+    assert_eq!(breakdown.remaining_budget, 17);
+}
+
+#[test]
+fn walls_block_router_coverage() {
+    // A router on the initial cell: the wall at [2, 8] hides every cell to
+    // its right, leaving columns 4 to 7 on rows 2 to 5.
+    let breakdown = example().score("0\n1\n2 7\n");
+    assert_eq!(breakdown.covered_targets, 4 * 4);
+}
+
+#[test]
+fn scoring_rejects_invalid_backbone() {
+    let example = example();
+    assert_eq!(
+        example.score_error("1\n5 5\n0\n").code,
+        "disconnected-backbone-cell"
+    );
+    assert_eq!(
+        example.score_error("1\n2 7\n0\n").code,
+        "duplicate-backbone-cell"
+    );
+    assert_eq!(
+        example.score_error("1\n8 0\n0\n").code,
+        "cell-out-of-bounds"
+    );
+}
+
+#[test]
+fn scoring_rejects_invalid_routers() {
+    let example = example();
+    assert_eq!(
+        example.score_error("0\n1\n3 6\n").code,
+        "router-not-on-backbone"
+    );
+    assert_eq!(
+        example.score_error("1\n1 7\n1\n1 7\n").code,
+        "router-on-wall"
+    );
+    assert_eq!(
+        example.score_error("0\n2\n2 7\n2 7\n").code,
+        "duplicate-router"
+    );
+    assert_eq!(
+        example.score_error("1\n3 6\n3\n2 7\n3 6\n3 6\n").code,
+        "duplicate-router"
+    );
+}
+
+#[test]
+fn scoring_rejects_budget_overrun() {
+    let err = example().score_error("2\n3 6\n3 7\n3\n2 7\n3 6\n3 7\n");
+    assert_eq!(err.code, "budget-exceeded");
+}
+
+#[test]
+fn submission_parser_requires_router_list() {
+    let err = parse_submission("1\n3 6\n").expect_err("should fail");
+    assert_eq!(err.code, "missing-value");
+}
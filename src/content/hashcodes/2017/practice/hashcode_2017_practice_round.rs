@@ -14,25 +14,58 @@ pub mod submission;
 mod tests;
 
 use crate::hashcodes::error::ProblemError;
-use crate::hashcodes::response::ScoreResponse;
-use input::{load_input, parse_input, ProblemInput};
-use scorer::score;
-use submission::{parse_submission, ProblemSubmission};
-
-pub fn score_submission(
-    input_file: &str,
-    submission_text: &str,
-) -> Result<ScoreResponse, ProblemError> {
-    let raw_input = load_input(input_file)?;
-    let parsed_input = parse_input(raw_input)?;
-    let submission = parse_submission(submission_text)?;
-    let breakdown = score(&parsed_input, &submission)?;
-    Ok(ScoreResponse::with_details(
-        "hashcode_2017_practice_round",
-        input_file,
-        breakdown.total_score as i64,
-        breakdown.into_json(),
-    ))
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2017 practice round.
+pub struct HashCode2017Practice;
+
+impl Problem for HashCode2017Practice {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2017_practice_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2017"
+    }
+
+    fn round(&self) -> &'static str {
+        "practice"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["practice_round", "practice_problem"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+
+    fn solver(&self) -> Option<fn(&ProblemInput) -> String> {
+        Some(|input| submission::format_submission(&solver::solve(input)))
+    }
 }
 
 /// Shared ingredient representation from the problem statement.
@@ -1,4 +1,5 @@
 use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{find_dataset, Dataset};
 
 use super::Ingredient;
 
@@ -11,22 +12,35 @@ pub struct ProblemInput {
     pub grid: Vec<Vec<Ingredient>>,
 }
 
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "example.in",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2017_practice_round.in/example.in"),
+    },
+    Dataset {
+        name: "b_small",
+        file: "small.in",
+        aliases: &["small"],
+        contents: include_str!("hashcode_2017_practice_round.in/small.in"),
+    },
+    Dataset {
+        name: "c_medium",
+        file: "medium.in",
+        aliases: &["medium"],
+        contents: include_str!("hashcode_2017_practice_round.in/medium.in"),
+    },
+    Dataset {
+        name: "d_big",
+        file: "big.in",
+        aliases: &["big"],
+        contents: include_str!("hashcode_2017_practice_round.in/big.in"),
+    },
+];
+
 pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
-    let normalized = input_file.trim().trim_end_matches(".in");
-    match normalized {
-        "a_example" | "example" => Ok(include_str!("hashcode_2017_practice_round.in/example.in")),
-        "b_small" | "small" => Ok(include_str!("hashcode_2017_practice_round.in/small.in")),
-        "c_medium" | "medium" => Ok(include_str!("hashcode_2017_practice_round.in/medium.in")),
-        "d_big" | "big" => Ok(include_str!("hashcode_2017_practice_round.in/big.in")),
-        other => Err(ProblemError::with_details(
-            "unknown-input-file",
-            format!("Unsupported 2017 practice dataset '{other}'"),
-            serde_json::json!({
-                "requested": input_file,
-                "available": ["a_example", "b_small", "c_medium", "d_big"],
-            }),
-        )),
-    }
+    find_dataset("2017", "practice", DATASETS, input_file).map(|dataset| dataset.contents)
 }
 
 pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
@@ -1,4 +1,5 @@
 use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
 
 use super::input::ProblemInput;
 use super::submission::ProblemSubmission;
@@ -12,8 +13,12 @@ pub struct ScoreBreakdown {
     pub covered_cells: usize,
 }
 
-impl ScoreBreakdown {
-    pub fn into_json(self) -> serde_json::Value {
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
         serde_json::json!({
             "total_score": self.total_score,
             "slice_count": self.slice_count,
@@ -27,6 +32,7 @@ impl ScoreBreakdown {
 /// - the number of cells per slice cannot exceed `L` (called `max_cells` here)
 /// - each slice has to contain at least `min_ingredient` of both tomatoes and mushrooms
 /// - slices must not overlap
+///
 /// The problem's score equals the sum of the slice areas, which in turn matches
 /// the number of covered cells when every rule is satisfied.
 pub fn score(
@@ -88,3 +88,15 @@ pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
 
     Ok(ProblemSubmission { slices })
 }
+
+/// Renders a submission in the statement's output format.
+pub fn format_submission(submission: &ProblemSubmission) -> String {
+    let mut out = format!("{}\n", submission.slices.len());
+    for slice in &submission.slices {
+        out.push_str(&format!(
+            "{} {} {} {}\n",
+            slice.start_row, slice.start_col, slice.end_row, slice.end_col
+        ));
+    }
+    out
+}
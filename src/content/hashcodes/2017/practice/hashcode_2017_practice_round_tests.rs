@@ -2,22 +2,18 @@ use super::input::{load_input, parse_input};
 use super::scorer::{score, ScoreBreakdown};
 use super::solver::solve;
 use super::submission::{parse_submission, ProblemSubmission};
-use super::Slice;
+use super::{HashCode2017Practice, Slice};
+use crate::hashcodes::test_support::Fixture;
 
-fn example_input() -> super::input::ProblemInput {
-    let raw_input = load_input("a_example").expect("dataset");
-    parse_input(raw_input).expect("parse input")
-}
-
-fn parse_submission_str(raw: &str) -> ProblemSubmission {
-    parse_submission(raw).expect("valid submission")
+fn example() -> Fixture<HashCode2017Practice> {
+    Fixture::dataset(HashCode2017Practice, "a_example")
 }
 
 #[test]
 fn solver_produces_valid_submission_for_example() {
-    let parsed_input = example_input();
-    let submission = solve(&parsed_input);
-    let breakdown = score(&parsed_input, &submission).expect("scoring should succeed");
+    let input = example().input;
+    let submission = solve(&input);
+    let breakdown = score(&input, &submission).expect("scoring should succeed");
     assert!(breakdown.total_score > 0);
     assert_eq!(breakdown.covered_cells, breakdown.total_score);
 }
@@ -108,15 +104,12 @@ fn submission_parser_detects_mismatched_count() {
 
 #[test]
 fn scoring_rejects_out_of_bounds_slice() {
-    let parsed_input = example_input();
-    let submission = parse_submission_str("1\n0 0 3 3\n");
-    let err = score(&parsed_input, &submission).expect_err("should fail");
+    let err = example().score_error("1\n0 0 3 3\n");
     assert_eq!(err.code, "slice-out-of-bounds");
 }
 
 #[test]
 fn scoring_rejects_inverted_slice() {
-    let parsed_input = example_input();
     let submission = ProblemSubmission {
         slices: vec![Slice {
             start_row: 2,
@@ -125,42 +118,68 @@ fn scoring_rejects_inverted_slice() {
             end_col: 1,
         }],
     };
-    let err = score(&parsed_input, &submission).expect_err("should fail");
+    let err = score(&example().input, &submission).expect_err("should fail");
     assert_eq!(err.code, "invalid-slice-orientation");
 }
 
 #[test]
 fn scoring_rejects_slice_too_large() {
-    let parsed_input = example_input();
-    let submission = parse_submission_str("1\n0 0 2 4\n");
-    let err = score(&parsed_input, &submission).expect_err("should fail");
+    let err = example().score_error("1\n0 0 2 4\n");
     assert_eq!(err.code, "slice-too-large");
 }
 
 #[test]
 fn scoring_rejects_not_enough_ingredients() {
-    let parsed_input = example_input();
     // Two tomato-only cells from the top row.
-    let submission = parse_submission_str("1\n0 0 0 1\n");
-    let err = score(&parsed_input, &submission).expect_err("should fail");
+    let err = example().score_error("1\n0 0 0 1\n");
     assert_eq!(err.code, "slice-not-enough-ingredients");
 }
 
 #[test]
 fn scoring_rejects_overlapping_slices() {
-    let parsed_input = example_input();
-    let overlapping = "2\n0 0 1 1\n0 1 1 2\n";
-    let submission = parse_submission(overlapping).expect("parse submission");
-    let err = score(&parsed_input, &submission).expect_err("should fail");
+    let err = example().score_error("2\n0 0 1 1\n0 1 1 2\n");
     assert_eq!(err.code, "overlapping-slices");
 }
 
 #[test]
 fn scoring_accepts_valid_slice_set() {
-    let parsed_input = example_input();
-    let submission = parse_submission_str("1\n1 1 2 3\n");
-    let breakdown: ScoreBreakdown = score(&parsed_input, &submission).expect("should score");
+    let breakdown: ScoreBreakdown = example().score("1\n1 1 2 3\n");
     assert_eq!(breakdown.total_score, 6);
     assert_eq!(breakdown.covered_cells, 6);
     assert_eq!(breakdown.slice_count, 1);
 }
+
+#[test]
+fn load_input_accepts_file_names_and_aliases() {
+    let canonical = load_input("a_example").expect("canonical name");
+    assert_eq!(load_input("example.in").expect("file name"), canonical);
+    assert_eq!(load_input(" example ").expect("alias"), canonical);
+}
+
+#[test]
+fn registry_routes_practice_aliases() {
+    use crate::hashcodes::ProblemKey;
+
+    let key = ProblemKey::from_route("2017", "practice_round").expect("registered");
+    assert_eq!(ProblemKey::from_route("2017", "practice"), Some(key));
+    assert_eq!(key.datasets().len(), 4);
+    let response = key
+        .score_submission("example.in", "1\n1 1 2 3\n")
+        .expect("should score");
+    assert_eq!(response.problem, "hashcode_2017_practice_round");
+    assert_eq!(response.score, 6);
+}
+
+#[test]
+fn registry_solver_output_round_trips_through_the_scorer() {
+    use crate::hashcodes::ProblemKey;
+
+    let key = ProblemKey::from_route("2017", "practice").expect("registered");
+    assert!(key.has_solver());
+    let text = key.solve("a_example").expect("should solve");
+    key.validate_submission(&text).expect("should parse");
+    let response = key
+        .score_submission("a_example", &text)
+        .expect("should score");
+    assert!(response.score > 0);
+}
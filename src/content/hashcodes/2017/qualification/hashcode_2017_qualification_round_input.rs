@@ -0,0 +1,118 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Endpoint {
+    /// Latency from the data center in milliseconds.
+    pub data_center_latency: u64,
+    /// `(cache, latency)` pairs sorted by increasing latency.
+    pub connections: Vec<(usize, u64)>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Request {
+    pub video: usize,
+    pub endpoint: usize,
+    pub count: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub video_sizes: Vec<u64>,
+    pub endpoints: Vec<Endpoint>,
+    pub requests: Vec<Request>,
+    pub cache_count: usize,
+    pub cache_capacity: u64,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "me_at_the_zoo",
+        file: "me_at_the_zoo.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2017_qualification_round.in/me_at_the_zoo.in"),
+    },
+    Dataset {
+        name: "videos_worth_spreading",
+        file: "videos_worth_spreading.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2017_qualification_round.in/videos_worth_spreading.in"),
+    },
+    Dataset {
+        name: "trending_today",
+        file: "trending_today.in",
+        aliases: &[],
+        contents: include_str!("hashcode_2017_qualification_round.in/trending_today.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2017", "qualification", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let video_count = tokens.parse::<usize>("videos")?;
+    let endpoint_count = tokens.parse::<usize>("endpoints")?;
+    let request_count = tokens.parse::<usize>("requests")?;
+    let cache_count = tokens.parse::<usize>("caches")?;
+    let cache_capacity = tokens.parse::<u64>("cache_capacity")?;
+
+    let mut video_sizes = Vec::with_capacity(video_count);
+    for _ in 0..video_count {
+        video_sizes.push(tokens.parse::<u64>("video_size")?);
+    }
+
+    let mut endpoints = Vec::with_capacity(endpoint_count);
+    for index in 0..endpoint_count {
+        let data_center_latency = tokens.parse::<u64>("data_center_latency")?;
+        let connection_count = tokens.parse::<usize>("connections")?;
+        let mut connections = Vec::with_capacity(connection_count);
+        for _ in 0..connection_count {
+            let cache = tokens.parse::<usize>("cache")?;
+            let latency = tokens.parse::<u64>("cache_latency")?;
+            if cache >= cache_count {
+                return Err(ProblemError::with_details(
+                    "invalid-cache",
+                    format!("Endpoint {index} is connected to unknown cache {cache}"),
+                    serde_json::json!({ "endpoint": index, "cache": cache }),
+                ));
+            }
+            connections.push((cache, latency));
+        }
+        connections.sort_by_key(|&(cache, latency)| (latency, cache));
+        endpoints.push(Endpoint {
+            data_center_latency,
+            connections,
+        });
+    }
+
+    let mut requests = Vec::with_capacity(request_count);
+    for index in 0..request_count {
+        let video = tokens.parse::<usize>("request_video")?;
+        let endpoint = tokens.parse::<usize>("request_endpoint")?;
+        let count = tokens.parse::<u64>("request_count")?;
+        if video >= video_count || endpoint >= endpoint_count {
+            return Err(ProblemError::with_details(
+                "invalid-request",
+                format!("Request description {index} references an unknown video or endpoint"),
+                serde_json::json!({ "request": index, "video": video, "endpoint": endpoint }),
+            ));
+        }
+        requests.push(Request {
+            video,
+            endpoint,
+            count,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        video_sizes,
+        endpoints,
+        requests,
+        cache_count,
+        cache_capacity,
+    })
+}
@@ -0,0 +1,144 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub total_requests: u64,
+    pub requests_served_from_cache: u64,
+    pub time_saved_ms: u64,
+    /// Megabytes used in every cache server.
+    pub cache_usage: Vec<u64>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "total_score": self.total_score,
+            "total_requests": self.total_requests,
+            "requests_served_from_cache": self.requests_served_from_cache,
+            "time_saved_ms": self.time_saved_ms,
+            "cache_usage": self.cache_usage,
+        })
+    }
+}
+
+/// Validates the cache contents and computes the official score:
+/// - every cache id exists and is described at most once
+/// - a cache lists existing videos without repetitions
+/// - the summed video sizes stay within the cache capacity
+///
+/// Each request is streamed from the lowest-latency cache that is connected to
+/// its endpoint and holds the video; caches the endpoint is not connected to
+/// are never used. The score is the time saved in milliseconds, multiplied by
+/// 1000 and divided by the total number of requests, rounding down.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let video_count = input.video_sizes.len();
+    let words_per_cache = video_count.div_ceil(64);
+    // One bitset of stored videos per cache server.
+    let mut stored = vec![0u64; input.cache_count * words_per_cache];
+    let mut described = vec![false; input.cache_count];
+    let mut cache_usage = vec![0u64; input.cache_count];
+
+    for (line, contents) in submission.caches.iter().enumerate() {
+        let cache = contents.cache;
+        if cache >= input.cache_count {
+            return Err(ProblemError::with_details(
+                "unknown-cache",
+                format!(
+                    "Cache server {cache} does not exist; there are {count} caches",
+                    count = input.cache_count
+                ),
+                serde_json::json!({ "description": line, "cache": cache }),
+            ));
+        }
+        if described[cache] {
+            return Err(ProblemError::with_details(
+                "duplicate-cache",
+                format!("Cache server {cache} is described more than once"),
+                serde_json::json!({ "description": line, "cache": cache }),
+            ));
+        }
+        described[cache] = true;
+
+        let bits = &mut stored[cache * words_per_cache..(cache + 1) * words_per_cache];
+        for &video in &contents.videos {
+            if video >= video_count {
+                return Err(ProblemError::with_details(
+                    "unknown-video",
+                    format!("Cache server {cache} stores video {video} which does not exist"),
+                    serde_json::json!({ "cache": cache, "video": video }),
+                ));
+            }
+            let (word, mask) = (video / 64, 1u64 << (video % 64));
+            if bits[word] & mask != 0 {
+                return Err(ProblemError::with_details(
+                    "duplicate-video",
+                    format!("Cache server {cache} lists video {video} more than once"),
+                    serde_json::json!({ "cache": cache, "video": video }),
+                ));
+            }
+            bits[word] |= mask;
+            cache_usage[cache] += input.video_sizes[video];
+        }
+        if cache_usage[cache] > input.cache_capacity {
+            return Err(ProblemError::with_details(
+                "cache-capacity-exceeded",
+                format!(
+                    "Cache server {cache} stores {used}MB but only holds {capacity}MB",
+                    used = cache_usage[cache],
+                    capacity = input.cache_capacity
+                ),
+                serde_json::json!({
+                    "cache": cache,
+                    "used": cache_usage[cache],
+                    "capacity": input.cache_capacity,
+                }),
+            ));
+        }
+    }
+
+    let mut total_requests = 0u64;
+    let mut requests_served_from_cache = 0u64;
+    let mut time_saved_ms = 0u64;
+    for request in &input.requests {
+        total_requests += request.count;
+        let endpoint = &input.endpoints[request.endpoint];
+        let (offset, bit) = (request.video / 64, request.video % 64);
+        let best = endpoint
+            .connections
+            .iter()
+            .find(|&&(cache, _)| stored[cache * words_per_cache + offset] >> bit & 1 == 1);
+        if let Some(&(_, latency)) = best {
+            if latency < endpoint.data_center_latency {
+                requests_served_from_cache += request.count;
+                time_saved_ms += request.count * (endpoint.data_center_latency - latency);
+            }
+        }
+    }
+
+    let total_score = if total_requests == 0 {
+        0
+    } else {
+        (u128::from(time_saved_ms) * 1000 / u128::from(total_requests)) as u64
+    };
+
+    Ok(ScoreBreakdown {
+        total_score,
+        total_requests,
+        requests_served_from_cache,
+        time_saved_ms,
+        cache_usage,
+    })
+}
@@ -0,0 +1,70 @@
+use crate::hashcodes::error::ProblemError;
+
+/// Videos stored in a single cache server.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CacheContents {
+    pub cache: usize,
+    pub videos: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub caches: Vec<CacheContents>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut lines = raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or_else(|| {
+        ProblemError::new(
+            "missing-cache-count",
+            "Submission must start with the number of cache descriptions",
+        )
+    })?;
+    let declared_count = header.trim().parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-cache-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": header.trim() }),
+        )
+    })?;
+
+    let mut caches = Vec::with_capacity(declared_count.min(1 << 20));
+    for (index, line) in lines {
+        let numbers: Result<Vec<usize>, ProblemError> = line
+            .split_whitespace()
+            .map(|value| {
+                value.parse::<usize>().map_err(|_err| {
+                    ProblemError::with_details(
+                        "invalid-number",
+                        format!(
+                            "Value '{value}' on line {line_no} is not a non-negative integer",
+                            line_no = index + 1
+                        ),
+                        serde_json::json!({ "line": index + 1, "value": value }),
+                    )
+                })
+            })
+            .collect();
+        let numbers = numbers?;
+        caches.push(CacheContents {
+            cache: numbers[0],
+            videos: numbers[1..].to_vec(),
+        });
+    }
+
+    if caches.len() != declared_count {
+        return Err(ProblemError::with_details(
+            "cache-count-mismatch",
+            format!(
+                "Submission declares {declared_count} cache descriptions but provided {actual}",
+                actual = caches.len()
+            ),
+            serde_json::json!({ "declared": declared_count, "actual": caches.len() }),
+        ));
+    }
+
+    Ok(ProblemSubmission { caches })
+}
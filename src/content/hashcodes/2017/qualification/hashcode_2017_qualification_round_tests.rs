@@ -0,0 +1,75 @@
+use super::submission::parse_submission;
+use super::HashCode2017Qualification;
+use crate::hashcodes::test_support::Fixture;
+
+// The example from the statement: 5 videos, 2 endpoints, 4 request
+// descriptions and 3 caches of 100MB.
+const EXAMPLE: &str = "5 2 4 3 100
+50 50 80 30 110
+1000 3
+0 100
+2 200
+1 300
+500 0
+3 0 1500
+0 1 1000
+4 0 500
+1 0 1000
+";
+
+fn example() -> Fixture<HashCode2017Qualification> {
+    Fixture::inline(HashCode2017Qualification, EXAMPLE)
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2017Qualification, "me_at_the_zoo").input;
+    assert_eq!(parsed.video_sizes.len(), 100);
+    assert_eq!(parsed.cache_count, 10);
+}
+
+#[test]
+fn endpoint_connections_are_sorted_by_latency() {
+    let input = example().input;
+    assert_eq!(
+        input.endpoints[0].connections,
+        vec![(0, 100), (2, 200), (1, 300)]
+    );
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("3\n0 2\n1 3 1\n2 0 1\n");
+    assert_eq!(breakdown.total_score, 462500);
+    assert_eq!(breakdown.total_requests, 4000);
+    assert_eq!(breakdown.requests_served_from_cache, 2500);
+    assert_eq!(breakdown.cache_usage, vec![80, 80, 100]);
+}
+
+#[test]
+fn scoring_ignores_caches_not_connected_to_the_endpoint() {
+    // Endpoint 1 has no cache, so storing video 0 does not help it.
+    let breakdown = example().score("1\n0 0\n");
+    assert_eq!(breakdown.total_score, 0);
+}
+
+#[test]
+fn submission_parser_detects_count_mismatch() {
+    let err = parse_submission("2\n0 1\n").expect_err("should fail");
+    assert_eq!(err.code, "cache-count-mismatch");
+    let err = parse_submission("9223372036854775808\n").expect_err("should fail");
+    assert_eq!(err.code, "cache-count-mismatch");
+}
+
+#[test]
+fn scoring_rejects_invalid_cache_descriptions() {
+    let example = example();
+    assert_eq!(example.score_error("2\n0 1\n0 2\n").code, "duplicate-cache");
+    assert_eq!(example.score_error("1\n3 1\n").code, "unknown-cache");
+    assert_eq!(example.score_error("1\n0 5\n").code, "unknown-video");
+    assert_eq!(example.score_error("1\n0 1 1\n").code, "duplicate-video");
+    assert_eq!(
+        example.score_error("1\n0 0 2\n").code,
+        "cache-capacity-exceeded"
+    );
+}
@@ -0,0 +1,136 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProjectKind {
+    Residential { capacity: u64 },
+    Utility { service: usize },
+}
+
+/// A building project with its plan reduced to the occupied cells.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Project {
+    pub kind: ProjectKind,
+    pub rows: usize,
+    pub columns: usize,
+    /// Occupied `(row, column)` cells relative to the top-left corner.
+    pub occupied: Vec<(usize, usize)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub rows: usize,
+    pub columns: usize,
+    pub walking_distance: usize,
+    pub projects: Vec<Project>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.in",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2018_final_round.in/a_example.in"),
+    },
+    Dataset {
+        name: "b_short_walk",
+        file: "b_short_walk.in",
+        aliases: &["short_walk"],
+        contents: include_str!("hashcode_2018_final_round.in/b_short_walk.in"),
+    },
+    Dataset {
+        name: "c_going_green",
+        file: "c_going_green.in",
+        aliases: &["going_green"],
+        contents: include_str!("hashcode_2018_final_round.in/c_going_green.in"),
+    },
+    Dataset {
+        name: "d_wide_selection",
+        file: "d_wide_selection.in",
+        aliases: &["wide_selection"],
+        contents: include_str!("hashcode_2018_final_round.in/d_wide_selection.in"),
+    },
+    Dataset {
+        name: "e_precise_fit",
+        file: "e_precise_fit.in",
+        aliases: &["precise_fit"],
+        contents: include_str!("hashcode_2018_final_round.in/e_precise_fit.in"),
+    },
+    Dataset {
+        name: "f_different_footprints",
+        file: "f_different_footprints.in",
+        aliases: &["different_footprints"],
+        contents: include_str!("hashcode_2018_final_round.in/f_different_footprints.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2018", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let rows = tokens.parse::<usize>("rows")?;
+    let columns = tokens.parse::<usize>("columns")?;
+    let walking_distance = tokens.parse::<usize>("walking_distance")?;
+    let project_count = tokens.parse::<usize>("projects")?;
+
+    let mut projects = Vec::with_capacity(project_count);
+    for index in 0..project_count {
+        let kind = tokens.token("project_type")?;
+        let plan_rows = tokens.parse::<usize>("plan_rows")?;
+        let plan_columns = tokens.parse::<usize>("plan_columns")?;
+        let kind = match kind {
+            "R" => ProjectKind::Residential {
+                capacity: tokens.parse::<u64>("capacity")?,
+            },
+            "U" => ProjectKind::Utility {
+                service: tokens.parse::<usize>("service")?,
+            },
+            other => {
+                return Err(ProblemError::with_details(
+                    "invalid-project-type",
+                    format!("Project {index} has type '{other}' instead of 'R' or 'U'"),
+                    serde_json::json!({ "project": index, "value": other, "line": tokens.line() }),
+                ))
+            }
+        };
+
+        let mut occupied = Vec::new();
+        for row in 0..plan_rows {
+            let line = tokens.token("plan_row")?;
+            let invalid =
+                line.len() != plan_columns || line.bytes().any(|b| b != b'#' && b != b'.');
+            if invalid {
+                return Err(ProblemError::with_details(
+                    "invalid-plan",
+                    format!(
+                        "Row {row} of project {index} must contain {plan_columns} '#' or '.' cells"
+                    ),
+                    serde_json::json!({ "project": index, "row": row, "line": tokens.line() }),
+                ));
+            }
+            occupied.extend(
+                line.bytes()
+                    .enumerate()
+                    .filter(|&(_, cell)| cell == b'#')
+                    .map(|(column, _)| (row, column)),
+            );
+        }
+        projects.push(Project {
+            kind,
+            rows: plan_rows,
+            columns: plan_columns,
+            occupied,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        rows,
+        columns,
+        walking_distance,
+        projects,
+    })
+}
@@ -0,0 +1,226 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::{ProblemInput, Project, ProjectKind};
+use super::submission::ProblemSubmission;
+
+/// Score earned by one constructed residential building.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ResidentialScore {
+    pub building: usize,
+    pub project: usize,
+    pub utility_types: u64,
+    pub score: u64,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub residential_buildings: Vec<ResidentialScore>,
+    pub utility_buildings: usize,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let residential: Vec<serde_json::Value> = self
+            .residential_buildings
+            .iter()
+            .map(|entry| {
+                serde_json::json!({
+                    "building": entry.building,
+                    "project": entry.project,
+                    "utility_types": entry.utility_types,
+                    "score": entry.score,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "utility_buildings": self.utility_buildings,
+            "residential_buildings": residential,
+        })
+    }
+}
+
+const EMPTY: u32 = u32::MAX;
+
+/// Places every building and computes the official score:
+/// - every building uses an existing project
+/// - the whole plan of every building fits within the city
+/// - no city cell is occupied by more than one building
+///
+/// A residential building earns its capacity once for every distinct utility
+/// type with a building at Manhattan distance at most `D`, measured between
+/// the nearest occupied cells of the two buildings.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let columns = input.columns;
+    let mut owner = vec![EMPTY; input.rows * columns];
+    // Service type plus one for every cell occupied by a utility building.
+    let mut services = vec![0u32; input.rows * columns];
+    let mut service_count = 0;
+
+    for (index, building) in submission.buildings.iter().enumerate() {
+        let project = input.projects.get(building.project).ok_or_else(|| {
+            ProblemError::with_details(
+                "unknown-project",
+                format!(
+                    "Building {index} uses project {project} which does not exist",
+                    project = building.project
+                ),
+                serde_json::json!({ "building": index, "project": building.project }),
+            )
+        })?;
+        if building.row >= input.rows
+            || project.rows > input.rows - building.row
+            || building.column >= input.columns
+            || project.columns > input.columns - building.column
+        {
+            return Err(ProblemError::with_details(
+                "building-out-of-bounds",
+                format!(
+                    "Building {index} placed at [{row}, {column}] does not fit in the city",
+                    row = building.row,
+                    column = building.column
+                ),
+                serde_json::json!({
+                    "building": index,
+                    "project": building.project,
+                    "row": building.row,
+                    "column": building.column,
+                }),
+            ));
+        }
+        let service = match project.kind {
+            ProjectKind::Utility { service } => {
+                service_count = service_count.max(service + 1);
+                service as u32 + 1
+            }
+            ProjectKind::Residential { .. } => 0,
+        };
+        for &(row, column) in &project.occupied {
+            let (row, column) = (building.row + row, building.column + column);
+            let cell = row * columns + column;
+            if owner[cell] != EMPTY {
+                return Err(ProblemError::with_details(
+                    "overlapping-buildings",
+                    format!(
+                        "Buildings {other} and {index} both occupy cell [{row}, {column}]",
+                        other = owner[cell]
+                    ),
+                    serde_json::json!({
+                        "building": index,
+                        "other_building": owner[cell],
+                        "row": row,
+                        "column": column,
+                    }),
+                ));
+            }
+            owner[cell] = index as u32;
+            services[cell] = service;
+        }
+    }
+
+    let mut reach: Vec<Option<Vec<(isize, isize)>>> = vec![None; input.projects.len()];
+    let mut seen = vec![usize::MAX; service_count];
+    let mut residential_buildings = Vec::new();
+    let mut utility_buildings = 0;
+    let mut total_score = 0;
+    for (index, building) in submission.buildings.iter().enumerate() {
+        let project = &input.projects[building.project];
+        let capacity = match project.kind {
+            ProjectKind::Residential { capacity } => capacity,
+            ProjectKind::Utility { .. } => {
+                utility_buildings += 1;
+                continue;
+            }
+        };
+        let offsets = reach[building.project]
+            .get_or_insert_with(|| walking_offsets(project, input.walking_distance));
+
+        let mut utility_types = 0;
+        for &(delta_row, delta_column) in offsets.iter() {
+            let row = building.row as isize + delta_row;
+            let column = building.column as isize + delta_column;
+            if row < 0 || column < 0 || row >= input.rows as isize || column >= columns as isize {
+                continue;
+            }
+            let service = services[row as usize * columns + column as usize];
+            if service != 0 && seen[service as usize - 1] != index {
+                seen[service as usize - 1] = index;
+                utility_types += 1;
+            }
+        }
+        let score = capacity * utility_types;
+        total_score += score;
+        residential_buildings.push(ResidentialScore {
+            building: index,
+            project: building.project,
+            utility_types,
+            score,
+        });
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        residential_buildings,
+        utility_buildings,
+    })
+}
+
+/// Offsets from the top-left corner of `project` to every cell outside its
+/// occupied cells that lies within `distance` of one of them.
+///
+/// Only cells on the border of the plan are expanded: the shortest path from
+/// an inner cell to any outside cell passes through a border cell that is at
+/// least as close.
+fn walking_offsets(project: &Project, distance: usize) -> Vec<(isize, isize)> {
+    // Pad by one more cell so border checks never leave the local grid.
+    let pad = distance + 1;
+    let rows = project.rows + 2 * pad;
+    let columns = project.columns + 2 * pad;
+    let mut occupied = vec![false; rows * columns];
+    for &(row, column) in &project.occupied {
+        occupied[(row + pad) * columns + column + pad] = true;
+    }
+
+    let mut reachable = vec![false; rows * columns];
+    for &(row, column) in &project.occupied {
+        let (row, column) = (row + pad, column + pad);
+        let border = [
+            (row - 1, column),
+            (row + 1, column),
+            (row, column - 1),
+            (row, column + 1),
+        ]
+        .iter()
+        .any(|&(r, c)| !occupied[r * columns + c]);
+        if !border {
+            continue;
+        }
+        for r in row - distance..=row + distance {
+            let span = distance - r.abs_diff(row);
+            for c in column - span..=column + span {
+                reachable[r * columns + c] = true;
+            }
+        }
+    }
+
+    let mut offsets = Vec::new();
+    for r in 0..rows {
+        for c in 0..columns {
+            let cell = r * columns + c;
+            if reachable[cell] && !occupied[cell] {
+                offsets.push((r as isize - pad as isize, c as isize - pad as isize));
+            }
+        }
+    }
+    offsets
+}
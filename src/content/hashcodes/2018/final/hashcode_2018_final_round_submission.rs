@@ -0,0 +1,47 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+/// A constructed building: project index and top-left corner.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Building {
+    pub project: usize,
+    pub row: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub buildings: Vec<Building>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-building-count",
+            "Submission must start with the number of buildings",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-building-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+
+    let mut buildings = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        let project = tokens.parse::<usize>("project")?;
+        let row = tokens.parse::<usize>("row")?;
+        let column = tokens.parse::<usize>("column")?;
+        buildings.push(Building {
+            project,
+            row,
+            column,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { buildings })
+}
@@ -0,0 +1,121 @@
+use super::input::{parse_input, ProjectKind};
+use super::HashCode2018Final;
+use crate::hashcodes::test_support::Fixture;
+
+// The example from the statement: a 4x7 city, walking distance 2, one
+// residential project and two utilities of types 1 and 5.
+const EXAMPLE: &str = "4 7 2 3
+R 3 2 25
+.#
+##
+.#
+U 1 4 1
+####
+U 2 2 5
+##
+##
+";
+
+fn example() -> Fixture<HashCode2018Final> {
+    Fixture::inline(HashCode2018Final, EXAMPLE)
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2018Final, "f_different_footprints").input;
+    assert_eq!(
+        (parsed.rows, parsed.columns, parsed.walking_distance),
+        (1000, 1000, 10)
+    );
+    assert_eq!(parsed.projects.len(), 180);
+}
+
+#[test]
+fn input_parser_keeps_occupied_cells() {
+    let input = example().input;
+    assert_eq!(
+        input.projects[0].kind,
+        ProjectKind::Residential { capacity: 25 }
+    );
+    assert_eq!(
+        input.projects[0].occupied,
+        vec![(0, 1), (1, 0), (1, 1), (2, 1)]
+    );
+    assert_eq!(input.projects[2].kind, ProjectKind::Utility { service: 5 });
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("4\n0 0 0\n1 3 0\n2 0 2\n0 0 5\n");
+    assert_eq!(breakdown.total_score, 75);
+    assert_eq!(breakdown.utility_buildings, 2);
+    let scores: Vec<(usize, u64)> = breakdown
+        .residential_buildings
+        .iter()
+        .map(|entry| (entry.building, entry.score))
+        .collect();
+    assert_eq!(scores, vec![(0, 50), (3, 25)]);
+}
+
+#[test]
+fn same_utility_type_counts_once() {
+    let city = Fixture::inline(HashCode2018Final, "3 6 1 2\nR 1 1 10\n#\nU 1 1 7\n#\n");
+    let breakdown = city.score("3\n0 1 1\n1 0 1\n1 1 2\n");
+    assert_eq!(breakdown.total_score, 10);
+}
+
+#[test]
+fn distance_is_measured_between_nearest_cells() {
+    // The utility at column 3 is out of reach of the single cell at column 0,
+    // but within 2 of the wide residential's far end at column 2.
+    let city = Fixture::inline(
+        HashCode2018Final,
+        "1 8 2 3\nR 1 1 10\n#\nR 1 3 10\n###\nU 1 1 1\n#\n",
+    );
+    assert_eq!(city.score("2\n0 0 0\n2 0 3\n").total_score, 0);
+    assert_eq!(city.score("2\n1 0 0\n2 0 4\n").total_score, 10);
+}
+
+#[test]
+fn scoring_rejects_invalid_placements() {
+    let example = example();
+    assert_eq!(example.score_error("1\n3 0 0\n").code, "unknown-project");
+    assert_eq!(
+        example.score_error("1\n1 0 4\n").code,
+        "building-out-of-bounds"
+    );
+    assert_eq!(
+        example.score_error("1\n0 2 0\n").code,
+        "building-out-of-bounds"
+    );
+    assert_eq!(
+        example.score_error("1\n0 18446744073709551615 0\n").code,
+        "building-out-of-bounds"
+    );
+    assert_eq!(
+        example.score_error("1\n0 0 18446744073709551615\n").code,
+        "building-out-of-bounds"
+    );
+    assert_eq!(
+        example.score_error("2\n0 0 0\n2 1 0\n").code,
+        "overlapping-buildings"
+    );
+}
+
+#[test]
+fn free_plan_cells_may_overlap() {
+    let example = example();
+    let err = example.score_error("2\n2 0 0\n0 0 1\n");
+    assert_eq!(err.code, "overlapping-buildings");
+    // Moved one row down, only the residential's free cell [0, 0] sits on
+    // the utility's occupied cell [1, 1].
+    example.score("2\n2 0 0\n0 1 1\n");
+}
+
+#[test]
+fn input_parser_rejects_bad_plans() {
+    let err = parse_input("1 1 1 1\nX 1 1 1\n#\n").expect_err("should fail");
+    assert_eq!(err.code, "invalid-project-type");
+    let err = parse_input("1 1 1 1\nR 1 2 1\n#\n").expect_err("should fail");
+    assert_eq!(err.code, "invalid-plan");
+}
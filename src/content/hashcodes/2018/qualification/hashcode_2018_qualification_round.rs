@@ -0,0 +1,71 @@
+//! Google Hash Code 2018 Qualification Round ("Self-driving rides") implementation.
+
+#[path = "hashcode_2018_qualification_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2018_qualification_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2018_qualification_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2018_qualification_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2018 qualification round.
+pub struct HashCode2018Qualification;
+
+impl Problem for HashCode2018Qualification {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2018_qualification_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2018"
+    }
+
+    fn round(&self) -> &'static str {
+        "qualification"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["qualification_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
+
+/// Intersection as `(row, column)`.
+pub type Intersection = (u64, u64);
+
+/// Number of steps needed to drive between two intersections.
+pub fn distance(from: Intersection, to: Intersection) -> u64 {
+    from.0.abs_diff(to.0) + from.1.abs_diff(to.1)
+}
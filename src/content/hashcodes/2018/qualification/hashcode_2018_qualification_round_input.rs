@@ -0,0 +1,100 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+use super::Intersection;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ride {
+    pub start: Intersection,
+    pub finish: Intersection,
+    pub earliest_start: u64,
+    pub latest_finish: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub rows: u64,
+    pub columns: u64,
+    pub vehicles: usize,
+    pub bonus: u64,
+    pub steps: u64,
+    pub rides: Vec<Ride>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.in",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2018_qualification_round.in/a_example.in"),
+    },
+    Dataset {
+        name: "b_should_be_easy",
+        file: "b_should_be_easy.in",
+        aliases: &["should_be_easy"],
+        contents: include_str!("hashcode_2018_qualification_round.in/b_should_be_easy.in"),
+    },
+    Dataset {
+        name: "c_no_hurry",
+        file: "c_no_hurry.in",
+        aliases: &["no_hurry"],
+        contents: include_str!("hashcode_2018_qualification_round.in/c_no_hurry.in"),
+    },
+    Dataset {
+        name: "d_metropolis",
+        file: "d_metropolis.in",
+        aliases: &["metropolis"],
+        contents: include_str!("hashcode_2018_qualification_round.in/d_metropolis.in"),
+    },
+    Dataset {
+        name: "e_high_bonus",
+        file: "e_high_bonus.in",
+        aliases: &["high_bonus"],
+        contents: include_str!("hashcode_2018_qualification_round.in/e_high_bonus.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2018", "qualification", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let rows = tokens.parse::<u64>("rows")?;
+    let columns = tokens.parse::<u64>("columns")?;
+    let vehicles = tokens.parse::<usize>("vehicles")?;
+    let ride_count = tokens.parse::<usize>("rides")?;
+    let bonus = tokens.parse::<u64>("bonus")?;
+    let steps = tokens.parse::<u64>("steps")?;
+
+    let mut rides = Vec::with_capacity(ride_count);
+    for _ in 0..ride_count {
+        let start = (
+            tokens.parse::<u64>("start_row")?,
+            tokens.parse::<u64>("start_column")?,
+        );
+        let finish = (
+            tokens.parse::<u64>("finish_row")?,
+            tokens.parse::<u64>("finish_column")?,
+        );
+        let earliest_start = tokens.parse::<u64>("earliest_start")?;
+        let latest_finish = tokens.parse::<u64>("latest_finish")?;
+        rides.push(Ride {
+            start,
+            finish,
+            earliest_start,
+            latest_finish,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        rows,
+        columns,
+        vehicles,
+        bonus,
+        steps,
+        rides,
+    })
+}
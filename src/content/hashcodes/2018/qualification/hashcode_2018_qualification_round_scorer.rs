@@ -0,0 +1,124 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::distance;
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub distance_score: u64,
+    pub bonus_score: u64,
+    pub rides_on_time: usize,
+    pub rides_with_bonus: usize,
+    /// Steps each vehicle spends waiting or parked after its last ride.
+    pub vehicle_idle_time: Vec<u64>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "total_score": self.total_score,
+            "distance_score": self.distance_score,
+            "bonus_score": self.bonus_score,
+            "rides_on_time": self.rides_on_time,
+            "rides_with_bonus": self.rides_with_bonus,
+            "vehicle_idle_time": self.vehicle_idle_time,
+        })
+    }
+}
+
+/// Simulates every vehicle and computes the official score:
+/// - the submission has one line per vehicle
+/// - every ride exists and is assigned at most once across all vehicles
+///
+/// Each vehicle starts at `[0, 0]`, drives to the next ride's start, waits for
+/// its earliest start and drives to its finish. A ride finishing no later than
+/// its latest finish earns its distance, plus the bonus `B` when it started
+/// exactly at its earliest start. Rides that would finish after the last step
+/// `T` are ignored.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    if submission.vehicles.len() != input.vehicles {
+        return Err(ProblemError::with_details(
+            "vehicle-count-mismatch",
+            format!(
+                "Submission describes {actual} vehicles but the fleet has {expected}",
+                actual = submission.vehicles.len(),
+                expected = input.vehicles
+            ),
+            serde_json::json!({ "expected": input.vehicles, "actual": submission.vehicles.len() }),
+        ));
+    }
+
+    let mut assigned_to: Vec<Option<usize>> = vec![None; input.rides.len()];
+    for (vehicle, rides) in submission.vehicles.iter().enumerate() {
+        for &ride in rides {
+            let Some(slot) = assigned_to.get_mut(ride) else {
+                return Err(ProblemError::with_details(
+                    "unknown-ride",
+                    format!("Vehicle {vehicle} is assigned ride {ride} which does not exist"),
+                    serde_json::json!({ "vehicle": vehicle, "ride": ride }),
+                ));
+            };
+            if let Some(previous) = *slot {
+                return Err(ProblemError::with_details(
+                    "duplicate-ride",
+                    format!("Ride {ride} is assigned more than once"),
+                    serde_json::json!({ "ride": ride, "vehicle": vehicle, "first_vehicle": previous }),
+                ));
+            }
+            *slot = Some(vehicle);
+        }
+    }
+
+    let mut distance_score = 0;
+    let mut bonus_score = 0;
+    let mut rides_on_time = 0;
+    let mut rides_with_bonus = 0;
+    let mut vehicle_idle_time = Vec::with_capacity(input.vehicles);
+    for rides in &submission.vehicles {
+        let mut position = (0, 0);
+        let mut step = 0;
+        let mut driving = 0;
+        for &index in rides {
+            let ride = &input.rides[index];
+            let pickup = step + distance(position, ride.start);
+            let start = pickup.max(ride.earliest_start);
+            let length = distance(ride.start, ride.finish);
+            let finish = start + length;
+            if finish > input.steps {
+                break;
+            }
+            driving += (pickup - step) + length;
+            position = ride.finish;
+            step = finish;
+            if finish <= ride.latest_finish {
+                rides_on_time += 1;
+                distance_score += length;
+                if start == ride.earliest_start {
+                    rides_with_bonus += 1;
+                    bonus_score += input.bonus;
+                }
+            }
+        }
+        vehicle_idle_time.push(input.steps - driving);
+    }
+
+    Ok(ScoreBreakdown {
+        total_score: distance_score + bonus_score,
+        distance_score,
+        bonus_score,
+        rides_on_time,
+        rides_with_bonus,
+        vehicle_idle_time,
+    })
+}
@@ -0,0 +1,41 @@
+use crate::hashcodes::error::ProblemError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Ride numbers assigned to each vehicle, in driving order.
+    pub vehicles: Vec<Vec<usize>>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut vehicles = Vec::new();
+    for (index, line) in raw.lines().enumerate() {
+        let mut values = line.split_whitespace();
+        let Some(count) = values.next() else {
+            continue;
+        };
+        let line_no = index + 1;
+        let parse = |value: &str| {
+            value.parse::<usize>().map_err(|_err| {
+                ProblemError::with_details(
+                    "invalid-number",
+                    format!("Value '{value}' on line {line_no} is not a non-negative integer"),
+                    serde_json::json!({ "line": line_no, "value": value }),
+                )
+            })
+        };
+        let count = parse(count)?;
+        let rides = values.map(parse).collect::<Result<Vec<_>, _>>()?;
+        if rides.len() != count {
+            return Err(ProblemError::with_details(
+                "ride-count-mismatch",
+                format!(
+                    "Line {line_no} declares {count} rides but lists {actual}",
+                    actual = rides.len()
+                ),
+                serde_json::json!({ "line": line_no, "declared": count, "actual": rides.len() }),
+            ));
+        }
+        vehicles.push(rides);
+    }
+    Ok(ProblemSubmission { vehicles })
+}
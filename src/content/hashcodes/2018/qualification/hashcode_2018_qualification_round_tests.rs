@@ -0,0 +1,70 @@
+use super::submission::parse_submission;
+use super::HashCode2018Qualification;
+use crate::hashcodes::test_support::Fixture;
+
+// The example from the statement: a 3x4 city, 2 vehicles, 3 rides, bonus 2
+// and 10 steps.
+const EXAMPLE: &str = "3 4 2 3 2 10
+0 0 1 3 2 9
+1 2 1 0 0 9
+2 0 2 2 0 9
+";
+
+fn example() -> Fixture<HashCode2018Qualification> {
+    Fixture::inline(HashCode2018Qualification, EXAMPLE)
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2018Qualification, "b_should_be_easy").input;
+    assert_eq!(parsed.vehicles, 100);
+    assert_eq!(parsed.rides.len(), 300);
+    assert_eq!(parsed.steps, 25000);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("1 0\n2 2 1\n");
+    assert_eq!(breakdown.total_score, 10);
+    assert_eq!(breakdown.distance_score, 8);
+    assert_eq!(breakdown.bonus_score, 2);
+    assert_eq!(breakdown.rides_on_time, 3);
+    // Vehicle 0 waits two steps and drives four; vehicle 1 drives 2 + 2 + 1 + 2.
+    assert_eq!(breakdown.vehicle_idle_time, vec![6, 3]);
+}
+
+#[test]
+fn late_rides_earn_nothing_but_still_take_time() {
+    let rides = Fixture::inline(
+        HashCode2018Qualification,
+        "3 4 1 2 5 20\n2 3 0 0 0 5\n0 0 0 1 0 20\n",
+    );
+    let breakdown = rides.score("2 0 1\n");
+    // Ride 0 finishes at step 10, after its latest finish; ride 1 then starts
+    // at step 10 and earns its distance but no bonus.
+    assert_eq!(breakdown.distance_score, 1);
+    assert_eq!(breakdown.bonus_score, 0);
+}
+
+#[test]
+fn rides_past_the_last_step_are_ignored() {
+    let rides = Fixture::inline(HashCode2018Qualification, "3 4 1 1 5 4\n2 3 0 0 0 4\n");
+    let breakdown = rides.score("1 0\n");
+    assert_eq!(breakdown.total_score, 0);
+    assert_eq!(breakdown.vehicle_idle_time, vec![4]);
+}
+
+#[test]
+fn submission_parser_detects_count_mismatch() {
+    let err = parse_submission("2 0\n").expect_err("should fail");
+    assert_eq!(err.code, "ride-count-mismatch");
+}
+
+#[test]
+fn scoring_rejects_invalid_assignments() {
+    let example = example();
+    assert_eq!(example.score_error("1 0\n").code, "vehicle-count-mismatch");
+    assert_eq!(example.score_error("1 0\n1 3\n").code, "unknown-ride");
+    assert_eq!(example.score_error("1 0\n2 1 0\n").code, "duplicate-ride");
+    assert_eq!(example.score_error("0\n2 1 1\n").code, "duplicate-ride");
+}
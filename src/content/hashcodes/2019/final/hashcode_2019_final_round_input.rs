@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompiledFile {
+    pub name: String,
+    pub compile_time: u64,
+    pub replication_time: u64,
+    /// Indices of the files needed to compile this one; always earlier files.
+    pub dependencies: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Target {
+    pub file: usize,
+    pub deadline: u64,
+    pub points: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub files: Vec<CompiledFile>,
+    pub targets: Vec<Target>,
+    pub servers: usize,
+    /// Maps a file name to its index in `files`.
+    pub file_index: HashMap<String, usize>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.in",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2019_final_round.in/a_example.in"),
+    },
+    Dataset {
+        name: "b_narrow",
+        file: "b_narrow.in",
+        aliases: &["narrow"],
+        contents: include_str!("hashcode_2019_final_round.in/b_narrow.in"),
+    },
+    Dataset {
+        name: "c_urgent",
+        file: "c_urgent.in",
+        aliases: &["urgent"],
+        contents: include_str!("hashcode_2019_final_round.in/c_urgent.in"),
+    },
+    Dataset {
+        name: "d_typical",
+        file: "d_typical.in",
+        aliases: &["typical"],
+        contents: include_str!("hashcode_2019_final_round.in/d_typical.in"),
+    },
+    Dataset {
+        name: "e_intriguing",
+        file: "e_intriguing.in",
+        aliases: &["intriguing"],
+        contents: include_str!("hashcode_2019_final_round.in/e_intriguing.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2019", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let file_count = tokens.parse::<usize>("files")?;
+    let target_count = tokens.parse::<usize>("targets")?;
+    let servers = tokens.parse::<usize>("servers")?;
+
+    let mut files = Vec::with_capacity(file_count);
+    let mut file_index = HashMap::with_capacity(file_count);
+    for index in 0..file_count {
+        let name = tokens.token("file_name")?;
+        let compile_time = tokens.parse::<u64>("compile_time")?;
+        let replication_time = tokens.parse::<u64>("replication_time")?;
+        let dependency_count = tokens.parse::<usize>("dependencies")?;
+        let mut dependencies = Vec::with_capacity(dependency_count);
+        for _ in 0..dependency_count {
+            let dependency = tokens.token("dependency")?;
+            dependencies.push(lookup(&file_index, dependency, tokens.line())?);
+        }
+        file_index.insert(name.to_string(), index);
+        files.push(CompiledFile {
+            name: name.to_string(),
+            compile_time,
+            replication_time,
+            dependencies,
+        });
+    }
+
+    let mut targets = Vec::with_capacity(target_count);
+    for _ in 0..target_count {
+        let name = tokens.token("target")?;
+        let file = lookup(&file_index, name, tokens.line())?;
+        let deadline = tokens.parse::<u64>("deadline")?;
+        let points = tokens.parse::<u64>("goal_points")?;
+        targets.push(Target {
+            file,
+            deadline,
+            points,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        files,
+        targets,
+        servers,
+        file_index,
+    })
+}
+
+fn lookup(
+    file_index: &HashMap<String, usize>,
+    name: &str,
+    line: usize,
+) -> Result<usize, ProblemError> {
+    file_index.get(name).copied().ok_or_else(|| {
+        ProblemError::with_details(
+            "unknown-file",
+            format!("File '{name}' on line {line} has not been described"),
+            serde_json::json!({ "file": name, "line": line }),
+        )
+    })
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Outcome of one target file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TargetResult {
+    pub file: String,
+    pub deadline: u64,
+    /// First time the file finished compiling on any server.
+    pub completed_at: Option<u64>,
+    /// Sum of compile times along the longest dependency chain ending here,
+    /// i.e. the earliest possible completion time.
+    pub critical_path: u64,
+    pub score: u64,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub targets: Vec<TargetResult>,
+    /// Longest critical path over all targets.
+    pub critical_path: u64,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let targets: Vec<serde_json::Value> = self
+            .targets
+            .iter()
+            .map(|target| {
+                serde_json::json!({
+                    "file": target.file,
+                    "deadline": target.deadline,
+                    "completed_at": target.completed_at,
+                    "critical_path": target.critical_path,
+                    "score": target.score,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "critical_path": self.critical_path,
+            "targets": targets,
+        })
+    }
+}
+
+/// Simulates the compilation steps and computes the official score:
+/// - every step compiles a known file on an existing server
+/// - every dependency of a step is compiled by some step, and becomes
+///   available on the step's server without waiting on a later step there
+///
+/// Each server runs its own steps in submission order, one at a time; the
+/// order of steps on different servers does not matter. A step starts once
+/// its server is idle and every dependency is available there: immediately
+/// after it was compiled on the same server, or after it was compiled
+/// elsewhere and its replication time has passed. A target compiled at
+/// second `x` no later than its deadline `d` earns its goal points plus
+/// `d - x` speed points.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let mut queues: Vec<Vec<usize>> = vec![Vec::new(); input.servers];
+    let mut files = Vec::with_capacity(submission.steps.len());
+    for (step, compilation) in submission.steps.iter().enumerate() {
+        let file = *input.file_index.get(&compilation.file).ok_or_else(|| {
+            ProblemError::with_details(
+                "unknown-file",
+                format!(
+                    "Step {step} compiles '{name}' which is not a compiled file",
+                    name = compilation.file
+                ),
+                serde_json::json!({ "step": step, "file": compilation.file }),
+            )
+        })?;
+        let server = compilation.server;
+        if server >= input.servers {
+            return Err(ProblemError::with_details(
+                "invalid-server",
+                format!(
+                    "Step {step} uses server {server} but there are {count} servers",
+                    count = input.servers
+                ),
+                serde_json::json!({ "step": step, "file": compilation.file, "server": server }),
+            ));
+        }
+        queues[server].push(step);
+        files.push(file);
+    }
+
+    let mut compiled = vec![false; input.files.len()];
+    for &file in &files {
+        compiled[file] = true;
+    }
+    for (step, &file) in files.iter().enumerate() {
+        let missing = input.files[file]
+            .dependencies
+            .iter()
+            .find(|&&dependency| !compiled[dependency]);
+        if let Some(&dependency) = missing {
+            let compilation = &submission.steps[step];
+            return Err(ProblemError::with_details(
+                "missing-dependency",
+                format!(
+                    "Step {step} compiles '{name}' but no step compiles its dependency \
+                     '{dependency}'",
+                    name = compilation.file,
+                    dependency = input.files[dependency].name
+                ),
+                serde_json::json!({
+                    "step": step,
+                    "file": compilation.file,
+                    "server": compilation.server,
+                    "dependency": input.files[dependency].name,
+                }),
+            ));
+        }
+    }
+
+    let mut server_free = vec![0u64; input.servers];
+    let mut next = vec![0usize; input.servers];
+    // Earliest time each file is available on every server.
+    let mut replicated = vec![u64::MAX; input.files.len()];
+    let mut first_compiled = vec![u64::MAX; input.files.len()];
+    let mut local: HashMap<(usize, usize), u64> = HashMap::new();
+
+    loop {
+        // Run the waiting step that can start first. Anything still to be
+        // simulated starts no earlier, so it cannot make that step's
+        // dependencies available any sooner.
+        let mut earliest: Option<(u64, usize)> = None;
+        for server in 0..input.servers {
+            let Some(&step) = queues[server].get(next[server]) else {
+                continue;
+            };
+            let start = input.files[files[step]].dependencies.iter().try_fold(
+                server_free[server],
+                |start, &dependency| {
+                    available(&local, &replicated, dependency, server).map(|time| start.max(time))
+                },
+            );
+            if let Some(start) = start {
+                if earliest.is_none_or(|(best, _)| start < best) {
+                    earliest = Some((start, server));
+                }
+            }
+        }
+        let Some((start, server)) = earliest else {
+            break;
+        };
+
+        let file = files[queues[server][next[server]]];
+        next[server] += 1;
+        let finish = start + input.files[file].compile_time;
+        server_free[server] = finish;
+        let here = local.entry((file, server)).or_insert(u64::MAX);
+        *here = (*here).min(finish);
+        first_compiled[file] = first_compiled[file].min(finish);
+        replicated[file] = replicated[file].min(finish + input.files[file].replication_time);
+    }
+
+    // Every server left with steps waits on a dependency that only a step
+    // still waiting itself could provide.
+    let blocked = (0..input.servers)
+        .filter_map(|server| queues[server].get(next[server]).map(|&step| (step, server)))
+        .min();
+    if let Some((step, server)) = blocked {
+        let compilation = &submission.steps[step];
+        let dependency = input.files[files[step]]
+            .dependencies
+            .iter()
+            .find(|&&dependency| available(&local, &replicated, dependency, server).is_none())
+            .map_or("", |&dependency| input.files[dependency].name.as_str());
+        return Err(ProblemError::with_details(
+            "unavailable-dependency",
+            format!(
+                "Step {step} compiles '{name}' on server {server}, but its dependency \
+                 '{dependency}' never becomes available there",
+                name = compilation.file
+            ),
+            serde_json::json!({
+                "step": step,
+                "file": compilation.file,
+                "server": server,
+                "dependency": dependency,
+            }),
+        ));
+    }
+
+    let mut longest_chain = vec![0u64; input.files.len()];
+    for (index, file) in input.files.iter().enumerate() {
+        let before = file
+            .dependencies
+            .iter()
+            .map(|&dependency| longest_chain[dependency])
+            .max()
+            .unwrap_or(0);
+        longest_chain[index] = before + file.compile_time;
+    }
+
+    let mut total_score = 0;
+    let mut critical_path = 0;
+    let mut targets = Vec::with_capacity(input.targets.len());
+    for target in &input.targets {
+        let completed_at = Some(first_compiled[target.file]).filter(|&time| time != u64::MAX);
+        let score = match completed_at {
+            Some(time) if time <= target.deadline => target.points + (target.deadline - time),
+            _ => 0,
+        };
+        total_score += score;
+        critical_path = critical_path.max(longest_chain[target.file]);
+        targets.push(TargetResult {
+            file: input.files[target.file].name.clone(),
+            deadline: target.deadline,
+            completed_at,
+            critical_path: longest_chain[target.file],
+            score,
+        });
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        targets,
+        critical_path,
+    })
+}
+
+/// When `file` can first be used on `server`, if any step has produced it yet.
+fn available(
+    local: &HashMap<(usize, usize), u64>,
+    replicated: &[u64],
+    file: usize,
+    server: usize,
+) -> Option<u64> {
+    let here = local.get(&(file, server)).copied().unwrap_or(u64::MAX);
+    Some(here.min(replicated[file])).filter(|&time| time != u64::MAX)
+}
@@ -0,0 +1,41 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+/// One `file server` compilation step, with the file still given by name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompilationStep {
+    pub file: String,
+    pub server: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub steps: Vec<CompilationStep>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-step-count",
+            "Submission must start with the number of compilation steps",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-step-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+
+    let mut steps = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        let file = tokens.token("file")?.to_string();
+        let server = tokens.parse::<usize>("server")?;
+        steps.push(CompilationStep { file, server });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { steps })
+}
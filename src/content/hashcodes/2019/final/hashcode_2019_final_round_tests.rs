@@ -0,0 +1,104 @@
+use super::submission::parse_submission;
+use super::HashCode2019Final;
+use crate::hashcodes::test_support::Fixture;
+
+const EXAMPLE_SUBMISSION: &str = "7\nc1 1\nc0 0\nc3 1\nc2 0\nc2 1\nc4 0\nc5 1\n";
+
+fn example() -> Fixture<HashCode2019Final> {
+    Fixture::dataset(HashCode2019Final, "a_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    for dataset in super::input::DATASETS {
+        let parsed = Fixture::dataset(HashCode2019Final, dataset.name).input;
+        assert!(!parsed.targets.is_empty(), "{}", dataset.name);
+    }
+}
+
+#[test]
+fn input_parser_resolves_dependencies() {
+    let input = example().input;
+    assert_eq!(input.servers, 2);
+    assert_eq!(input.files[4].dependencies, vec![1, 2]);
+    assert_eq!(input.targets[0].file, input.file_index["c3"]);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 60);
+    let completed: Vec<(&str, Option<u64>, u64)> = breakdown
+        .targets
+        .iter()
+        .map(|target| (target.file.as_str(), target.completed_at, target.score))
+        .collect();
+    assert_eq!(
+        completed,
+        vec![
+            ("c3", Some(23), 25),
+            ("c4", Some(50), 0),
+            ("c5", Some(53), 35)
+        ]
+    );
+}
+
+#[test]
+fn critical_path_follows_the_longest_chain() {
+    let breakdown = example().score("0\n");
+    // c0 -> c2 -> c4 takes 15 + 15 + 20 seconds.
+    assert_eq!(breakdown.critical_path, 50);
+    assert_eq!(breakdown.targets[0].critical_path, 23);
+    assert!(breakdown
+        .targets
+        .iter()
+        .all(|target| target.completed_at.is_none()));
+}
+
+#[test]
+fn dependencies_from_other_servers_wait_for_replication() {
+    let example = example();
+    // c1 finishes at 10 on server 0 and reaches server 1 after 18 more seconds.
+    let breakdown = example.score("2\nc1 0\nc3 1\n");
+    assert_eq!(breakdown.targets[0].completed_at, Some(41));
+    assert_eq!(breakdown.targets[0].score, 0);
+    // Compiled on the same server, c1 is usable immediately.
+    let breakdown = example.score("2\nc1 1\nc3 1\n");
+    assert_eq!(breakdown.targets[0].completed_at, Some(23));
+}
+
+#[test]
+fn steps_on_different_servers_may_come_in_any_order() {
+    // The statement example with server 0's steps first: c4 on server 0
+    // still waits for c1, listed later for server 1, to be replicated.
+    let breakdown = example().score("7\nc0 0\nc2 0\nc4 0\nc1 1\nc3 1\nc2 1\nc5 1\n");
+    assert_eq!(breakdown.total_score, 60);
+    assert_eq!(breakdown.targets[1].completed_at, Some(50));
+}
+
+#[test]
+fn scoring_rejects_dependencies_that_never_become_available() {
+    let example = example();
+    // c1 is only compiled after c3 on the same server.
+    let error = example.score_error("2\nc3 0\nc1 0\n");
+    assert_eq!(error.code, "unavailable-dependency");
+    assert_eq!(error.details.expect("details")["dependency"], "c1");
+    // c3 waits for c1 behind c2 on server 1, which waits for c0 behind c3.
+    let error = example.score_error("4\nc3 0\nc0 0\nc2 1\nc1 1\n");
+    assert_eq!(error.code, "unavailable-dependency");
+    assert_eq!(error.details.expect("details")["step"], 0);
+}
+
+#[test]
+fn scoring_rejects_invalid_steps() {
+    let example = example();
+    assert_eq!(example.score_error("1\nc2 0\n").code, "missing-dependency");
+    assert_eq!(example.score_error("1\nc9 0\n").code, "unknown-file");
+    assert_eq!(example.score_error("1\nc0 2\n").code, "invalid-server");
+}
+
+#[test]
+fn submission_parser_requires_every_step() {
+    let err = parse_submission("2\nc0 0\n").expect_err("should fail");
+    assert_eq!(err.code, "missing-value");
+}
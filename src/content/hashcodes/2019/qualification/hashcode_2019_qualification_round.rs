@@ -0,0 +1,63 @@
+//! Google Hash Code 2019 Qualification Round ("Photo slideshow") implementation.
+
+#[path = "hashcode_2019_qualification_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2019_qualification_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2019_qualification_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2019_qualification_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2019 qualification round.
+pub struct HashCode2019Qualification;
+
+impl Problem for HashCode2019Qualification {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2019_qualification_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2019"
+    }
+
+    fn round(&self) -> &'static str {
+        "qualification"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["qualification_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
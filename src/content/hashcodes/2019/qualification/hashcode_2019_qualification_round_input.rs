@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Photo {
+    pub orientation: Orientation,
+    /// Interned tag ids, sorted and without repetitions.
+    pub tags: Vec<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub photos: Vec<Photo>,
+    /// Number of distinct tags across all photos.
+    pub tag_count: usize,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.txt",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2019_qualification_round.in/a_example.txt"),
+    },
+    Dataset {
+        name: "c_memorable_moments",
+        file: "c_memorable_moments.txt",
+        aliases: &["memorable_moments"],
+        contents: include_str!("hashcode_2019_qualification_round.in/c_memorable_moments.txt"),
+    },
+    Dataset {
+        name: "d_pet_pictures",
+        file: "d_pet_pictures.txt",
+        aliases: &["pet_pictures"],
+        contents: include_str!("hashcode_2019_qualification_round.in/d_pet_pictures.txt"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2019", "qualification", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let photo_count = tokens.parse::<usize>("photos")?;
+
+    let mut interned: HashMap<&str, u32> = HashMap::new();
+    let mut photos = Vec::with_capacity(photo_count);
+    for index in 0..photo_count {
+        let orientation = match tokens.token("orientation")? {
+            "H" => Orientation::Horizontal,
+            "V" => Orientation::Vertical,
+            other => {
+                return Err(ProblemError::with_details(
+                    "invalid-orientation",
+                    format!("Photo {index} has orientation '{other}' instead of 'H' or 'V'"),
+                    serde_json::json!({ "photo": index, "value": other, "line": tokens.line() }),
+                ))
+            }
+        };
+        let tag_count = tokens.parse::<usize>("tag_count")?;
+        let mut tags = Vec::with_capacity(tag_count);
+        for _ in 0..tag_count {
+            let tag = tokens.token("tag")?;
+            let next_id = interned.len() as u32;
+            tags.push(*interned.entry(tag).or_insert(next_id));
+        }
+        tags.sort_unstable();
+        tags.dedup();
+        photos.push(Photo { orientation, tags });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        photos,
+        tag_count: interned.len(),
+    })
+}
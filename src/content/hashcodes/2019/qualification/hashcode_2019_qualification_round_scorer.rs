@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::{Orientation, ProblemInput};
+use super::submission::ProblemSubmission;
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub slides: usize,
+    /// Number of transitions for every interest factor that occurred.
+    pub transition_histogram: BTreeMap<u64, u64>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let histogram: Vec<serde_json::Value> = self
+            .transition_histogram
+            .iter()
+            .map(|(score, transitions)| {
+                serde_json::json!({ "score": score, "transitions": transitions })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "slides": self.slides,
+            "transition_histogram": histogram,
+        })
+    }
+}
+
+/// Validates the slideshow and computes the official score:
+/// - a slide shows either one horizontal photo or two vertical photos
+/// - every photo exists and is used at most once
+///
+/// Every transition between neighbouring slides scores the minimum of the
+/// number of common tags, tags only on the left slide and tags only on the
+/// right slide.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let mut used = vec![false; input.photos.len()];
+    let mut previous: Option<Vec<u32>> = None;
+    let mut total_score = 0;
+    let mut transition_histogram = BTreeMap::new();
+
+    for (slide, photos) in submission.slides.iter().enumerate() {
+        for &photo in photos {
+            if photo >= input.photos.len() {
+                return Err(ProblemError::with_details(
+                    "unknown-photo",
+                    format!("Slide {slide} shows photo {photo} which does not exist"),
+                    serde_json::json!({ "slide": slide, "photo": photo }),
+                ));
+            }
+            if used[photo] {
+                return Err(ProblemError::with_details(
+                    "duplicate-photo",
+                    format!("Photo {photo} is used more than once (again on slide {slide})"),
+                    serde_json::json!({ "slide": slide, "photo": photo }),
+                ));
+            }
+            used[photo] = true;
+        }
+
+        let tags = match photos[..] {
+            [photo] => {
+                let photo = &input.photos[photo];
+                if photo.orientation != Orientation::Horizontal {
+                    return Err(slide_error(
+                        "single-vertical-photo",
+                        slide,
+                        photos,
+                        "must pair its vertical photo with another vertical photo",
+                    ));
+                }
+                photo.tags.clone()
+            }
+            [first, second] => {
+                let (first, second) = (&input.photos[first], &input.photos[second]);
+                match (first.orientation, second.orientation) {
+                    (Orientation::Vertical, Orientation::Vertical) => {
+                        merge_tags(&first.tags, &second.tags)
+                    }
+                    (Orientation::Horizontal, Orientation::Horizontal) => {
+                        return Err(slide_error(
+                            "two-horizontal-photos",
+                            slide,
+                            photos,
+                            "shows two horizontal photos",
+                        ))
+                    }
+                    _ => {
+                        return Err(slide_error(
+                            "mixed-orientation",
+                            slide,
+                            photos,
+                            "mixes a horizontal and a vertical photo",
+                        ))
+                    }
+                }
+            }
+            _ => return Err(slide_error("empty-slide", slide, photos, "shows no photo")),
+        };
+
+        if let Some(previous) = &previous {
+            let interest = interest_factor(previous, &tags);
+            total_score += interest;
+            *transition_histogram.entry(interest).or_insert(0) += 1;
+        }
+        previous = Some(tags);
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        slides: submission.slides.len(),
+        transition_histogram,
+    })
+}
+
+fn slide_error(code: &'static str, slide: usize, photos: &[usize], reason: &str) -> ProblemError {
+    ProblemError::with_details(
+        code,
+        format!("Slide {slide} {reason}"),
+        serde_json::json!({ "slide": slide, "photos": photos }),
+    )
+}
+
+/// Union of two sorted tag lists.
+fn merge_tags(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        match left[i].cmp(&right[j]) {
+            std::cmp::Ordering::Less => {
+                merged.push(left[i]);
+                i += 1;
+            }
+            std::cmp::Ordering::Greater => {
+                merged.push(right[j]);
+                j += 1;
+            }
+            std::cmp::Ordering::Equal => {
+                merged.push(left[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    merged
+}
+
+fn interest_factor(left: &[u32], right: &[u32]) -> u64 {
+    let (mut i, mut j, mut common) = (0, 0, 0);
+    while i < left.len() && j < right.len() {
+        match left[i].cmp(&right[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                common += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    common.min(left.len() - common).min(right.len() - common) as u64
+}
@@ -0,0 +1,71 @@
+use crate::hashcodes::error::ProblemError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Photo ids shown on every slide, in slideshow order.
+    pub slides: Vec<Vec<usize>>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut lines = raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or_else(|| {
+        ProblemError::new(
+            "missing-slide-count",
+            "Submission must start with the number of slides",
+        )
+    })?;
+    let declared_count = header.trim().parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-slide-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": header.trim() }),
+        )
+    })?;
+
+    let mut slides = Vec::with_capacity(declared_count.min(1 << 20));
+    for (index, line) in lines {
+        let photos = line
+            .split_whitespace()
+            .map(|value| {
+                value.parse::<usize>().map_err(|_err| {
+                    ProblemError::with_details(
+                        "invalid-number",
+                        format!(
+                            "Value '{value}' on line {line_no} is not a non-negative integer",
+                            line_no = index + 1
+                        ),
+                        serde_json::json!({ "line": index + 1, "value": value }),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if photos.len() > 2 {
+            return Err(ProblemError::with_details(
+                "too-many-photos",
+                format!(
+                    "Slide on line {line_no} shows {count} photos; a slide holds one or two",
+                    line_no = index + 1,
+                    count = photos.len()
+                ),
+                serde_json::json!({ "line": index + 1, "photos": photos }),
+            ));
+        }
+        slides.push(photos);
+    }
+
+    if slides.len() != declared_count {
+        return Err(ProblemError::with_details(
+            "slide-count-mismatch",
+            format!(
+                "Submission declares {declared_count} slides but provided {actual}",
+                actual = slides.len()
+            ),
+            serde_json::json!({ "declared": declared_count, "actual": slides.len() }),
+        ));
+    }
+
+    Ok(ProblemSubmission { slides })
+}
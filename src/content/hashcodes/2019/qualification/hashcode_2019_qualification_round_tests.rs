@@ -0,0 +1,66 @@
+use super::input::Orientation;
+use super::submission::parse_submission;
+use super::HashCode2019Qualification;
+use crate::hashcodes::test_support::Fixture;
+
+fn example() -> Fixture<HashCode2019Qualification> {
+    Fixture::dataset(HashCode2019Qualification, "a_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2019Qualification, "d_pet_pictures.txt").input;
+    assert_eq!(parsed.photos.len(), 90000);
+    assert_eq!(parsed.photos[0].orientation, Orientation::Horizontal);
+    assert_eq!(parsed.photos[0].tags.len(), 16);
+}
+
+#[test]
+fn input_parser_interns_tags() {
+    let input = example().input;
+    assert_eq!(input.tag_count, 6);
+    // "cat" is interned first and shared by photos 0 and 3.
+    assert_eq!(input.photos[0].tags, vec![0, 1, 2]);
+    assert!(input.photos[3].tags.contains(&0));
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("3\n0\n3\n1 2\n");
+    assert_eq!(breakdown.total_score, 2);
+    assert_eq!(
+        breakdown
+            .transition_histogram
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec![(1, 2)]
+    );
+}
+
+#[test]
+fn single_slide_scores_zero() {
+    let breakdown = example().score("1\n2 1\n");
+    assert_eq!(breakdown.total_score, 0);
+    assert!(breakdown.transition_histogram.is_empty());
+}
+
+#[test]
+fn submission_parser_rejects_malformed_slides() {
+    let err = parse_submission("2\n0\n").expect_err("should fail");
+    assert_eq!(err.code, "slide-count-mismatch");
+    let err = parse_submission("1\n1 2 0\n").expect_err("should fail");
+    assert_eq!(err.code, "too-many-photos");
+}
+
+#[test]
+fn scoring_rejects_invalid_slides() {
+    let example = example();
+    assert_eq!(
+        example.score_error("1\n0 3\n").code,
+        "two-horizontal-photos"
+    );
+    assert_eq!(example.score_error("1\n0 1\n").code, "mixed-orientation");
+    assert_eq!(example.score_error("1\n1\n").code, "single-vertical-photo");
+    assert_eq!(example.score_error("2\n0\n0\n").code, "duplicate-photo");
+    assert_eq!(example.score_error("1\n4\n").code, "unknown-photo");
+}
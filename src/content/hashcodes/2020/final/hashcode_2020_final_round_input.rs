@@ -0,0 +1,117 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+use super::Cell;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Task {
+    pub score: u64,
+    /// Assembly points to visit, in order.
+    pub points: Vec<Cell>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub width: usize,
+    pub height: usize,
+    pub arms: usize,
+    pub steps: usize,
+    pub mount_points: Vec<Cell>,
+    pub tasks: Vec<Task>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.txt",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2020_final_round.in/a_example.txt"),
+    },
+    Dataset {
+        name: "b_single_arm",
+        file: "b_single_arm.txt",
+        aliases: &["single_arm"],
+        contents: include_str!("hashcode_2020_final_round.in/b_single_arm.txt"),
+    },
+    Dataset {
+        name: "c_few_arms",
+        file: "c_few_arms.txt",
+        aliases: &["few_arms"],
+        contents: include_str!("hashcode_2020_final_round.in/c_few_arms.txt"),
+    },
+    Dataset {
+        name: "d_tight_schedule",
+        file: "d_tight_schedule.txt",
+        aliases: &["tight_schedule"],
+        contents: include_str!("hashcode_2020_final_round.in/d_tight_schedule.txt"),
+    },
+    Dataset {
+        name: "e_dense_workspace",
+        file: "e_dense_workspace.txt",
+        aliases: &["dense_workspace"],
+        contents: include_str!("hashcode_2020_final_round.in/e_dense_workspace.txt"),
+    },
+    Dataset {
+        name: "f_decentralized",
+        file: "f_decentralized.txt",
+        aliases: &["decentralized"],
+        contents: include_str!("hashcode_2020_final_round.in/f_decentralized.txt"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2020", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let width = tokens.parse::<usize>("width")?;
+    let height = tokens.parse::<usize>("height")?;
+    let arms = tokens.parse::<usize>("arms")?;
+    let mount_count = tokens.parse::<usize>("mount_points")?;
+    let task_count = tokens.parse::<usize>("tasks")?;
+    let steps = tokens.parse::<usize>("steps")?;
+
+    let mut mount_points = Vec::with_capacity(mount_count);
+    for _ in 0..mount_count {
+        mount_points.push(parse_cell(&mut tokens, width, height)?);
+    }
+
+    let mut tasks = Vec::with_capacity(task_count);
+    for _ in 0..task_count {
+        let score = tokens.parse::<u64>("task_score")?;
+        let point_count = tokens.parse::<usize>("assembly_points")?;
+        let mut points = Vec::with_capacity(point_count);
+        for _ in 0..point_count {
+            points.push(parse_cell(&mut tokens, width, height)?);
+        }
+        tasks.push(Task { score, points });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        width,
+        height,
+        arms,
+        steps,
+        mount_points,
+        tasks,
+    })
+}
+
+fn parse_cell(tokens: &mut Tokens<'_>, width: usize, height: usize) -> Result<Cell, ProblemError> {
+    let x = tokens.parse::<usize>("x")?;
+    let y = tokens.parse::<usize>("y")?;
+    if x >= width || y >= height {
+        return Err(ProblemError::with_details(
+            "invalid-cell",
+            format!(
+                "Cell [{x}, {y}] on line {line} lies outside the workspace",
+                line = tokens.line()
+            ),
+            serde_json::json!({ "x": x, "y": y, "line": tokens.line() }),
+        ));
+    }
+    Ok((x, y))
+}
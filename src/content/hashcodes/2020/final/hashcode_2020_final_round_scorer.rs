@@ -0,0 +1,263 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::{Instruction, ProblemSubmission};
+use super::Cell;
+
+/// Work done by one installed arm.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ArmResult {
+    pub mount: Cell,
+    pub completed_tasks: usize,
+    pub score: u64,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub completed_tasks: usize,
+    pub arms: Vec<ArmResult>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let arms: Vec<serde_json::Value> = self
+            .arms
+            .iter()
+            .enumerate()
+            .map(|(arm, result)| {
+                serde_json::json!({
+                    "arm": arm,
+                    "mount": [result.mount.0, result.mount.1],
+                    "completed_tasks": result.completed_tasks,
+                    "score": result.score,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "completed_tasks": self.completed_tasks,
+            "arms": arms,
+        })
+    }
+}
+
+const EMPTY: u32 = u32::MAX;
+
+/// Simulates the robotic arms step by step and computes the official score:
+/// - at most `R` arms, each installed on its own mount point
+/// - every task exists and is assigned to at most one arm
+/// - an arm has at most `L` instructions
+/// - a gripper never leaves the workspace, and never expands onto a mount
+///   point or a cell occupied by any arm, unless that cell is the gripper of
+///   another arm retracting during the same step
+/// - every arm completes all tasks assigned to it
+///
+/// Moving the gripper back onto the previous cell of its arm retracts it. An
+/// arm works on its tasks in order and advances a task whenever its gripper
+/// is on the next assembly point. The score is the sum of the completed tasks'
+/// scores.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let (width, height) = (input.width, input.height);
+    let cell_index = |(x, y): Cell| y * width + x;
+    let mut is_mount = vec![false; width * height];
+    for &mount in &input.mount_points {
+        is_mount[cell_index(mount)] = true;
+    }
+
+    if submission.arms.len() > input.arms {
+        return Err(ProblemError::with_details(
+            "too-many-arms",
+            format!(
+                "Submission installs {count} arms but only {max} are available",
+                count = submission.arms.len(),
+                max = input.arms
+            ),
+            serde_json::json!({ "arms": submission.arms.len(), "available": input.arms }),
+        ));
+    }
+
+    let mut occupant = vec![EMPTY; width * height];
+    let mut assigned_to = vec![usize::MAX; input.tasks.len()];
+    for (arm, plan) in submission.arms.iter().enumerate() {
+        let (x, y) = plan.mount;
+        if x >= width || y >= height || !is_mount[cell_index(plan.mount)] {
+            return Err(ProblemError::with_details(
+                "invalid-mount",
+                format!("Arm {arm} is installed at [{x}, {y}], which is not a mount point"),
+                serde_json::json!({ "arm": arm, "mount": [x, y] }),
+            ));
+        }
+        let mounted = occupant[cell_index(plan.mount)];
+        if mounted != EMPTY {
+            return Err(ProblemError::with_details(
+                "duplicate-mount",
+                format!("Arms {mounted} and {arm} are both installed at [{x}, {y}]"),
+                serde_json::json!({ "arms": [mounted, arm], "mount": [x, y] }),
+            ));
+        }
+        occupant[cell_index(plan.mount)] = arm as u32;
+
+        for &task in &plan.tasks {
+            if task >= input.tasks.len() {
+                return Err(ProblemError::with_details(
+                    "unknown-task",
+                    format!("Arm {arm} works on task {task} which does not exist"),
+                    serde_json::json!({ "arm": arm, "task": task }),
+                ));
+            }
+            if assigned_to[task] != usize::MAX {
+                return Err(ProblemError::with_details(
+                    "duplicate-task",
+                    format!("Task {task} is assigned more than once"),
+                    serde_json::json!({ "arms": [assigned_to[task], arm], "task": task }),
+                ));
+            }
+            assigned_to[task] = arm;
+        }
+
+        if plan.instructions.len() > input.steps {
+            return Err(ProblemError::with_details(
+                "too-many-instructions",
+                format!(
+                    "Arm {arm} has {count} instructions but the assembly lasts {steps} steps",
+                    count = plan.instructions.len(),
+                    steps = input.steps
+                ),
+                serde_json::json!({
+                    "arm": arm,
+                    "instructions": plan.instructions.len(),
+                    "steps": input.steps,
+                }),
+            ));
+        }
+    }
+
+    // Cells of every arm from the mount point to the gripper.
+    let mut paths: Vec<Vec<Cell>> = submission
+        .arms
+        .iter()
+        .map(|plan| vec![plan.mount])
+        .collect();
+    // Current task (index into the arm's task list) and assembly point.
+    let mut progress = vec![(0usize, 0usize); submission.arms.len()];
+    let mut moved = Vec::with_capacity(submission.arms.len());
+    let mut expansions = Vec::with_capacity(submission.arms.len());
+    let last_step = submission
+        .arms
+        .iter()
+        .map(|plan| plan.instructions.len())
+        .max()
+        .unwrap_or(0);
+
+    for step in 0..last_step {
+        moved.clear();
+        expansions.clear();
+        for (arm, plan) in submission.arms.iter().enumerate() {
+            let instruction = match plan.instructions.get(step) {
+                Some(Instruction::Wait) | None => continue,
+                Some(&instruction) => instruction,
+            };
+            let path = &paths[arm];
+            let (x, y) = path[path.len() - 1];
+            let (delta_x, delta_y) = instruction.delta();
+            let target = (
+                x.wrapping_add_signed(delta_x),
+                y.wrapping_add_signed(delta_y),
+            );
+            if target.0 >= width || target.1 >= height {
+                return Err(ProblemError::with_details(
+                    "out-of-bounds",
+                    format!("Arm {arm} leaves the workspace from [{x}, {y}] in step {step}"),
+                    serde_json::json!({ "step": step, "arm": arm, "from": [x, y] }),
+                ));
+            }
+            moved.push(arm);
+            if path.len() >= 2 && path[path.len() - 2] == target {
+                // Retractions free their cells before anyone expands.
+                occupant[cell_index((x, y))] = EMPTY;
+                paths[arm].pop();
+            } else {
+                expansions.push((arm, target));
+            }
+        }
+
+        for &(arm, target) in &expansions {
+            let (x, y) = target;
+            if is_mount[cell_index(target)] {
+                return Err(ProblemError::with_details(
+                    "mount-point-collision",
+                    format!("Arm {arm} expands onto the mount point [{x}, {y}] in step {step}"),
+                    serde_json::json!({ "step": step, "arm": arm, "cell": [x, y] }),
+                ));
+            }
+            let other = occupant[cell_index(target)];
+            if other != EMPTY {
+                return Err(ProblemError::with_details(
+                    "arm-collision",
+                    format!(
+                        "Arm {arm} expands onto [{x}, {y}] occupied by arm {other} in step {step}"
+                    ),
+                    serde_json::json!({ "step": step, "arms": [arm, other], "cell": [x, y] }),
+                ));
+            }
+            occupant[cell_index(target)] = arm as u32;
+            paths[arm].push(target);
+        }
+
+        for &arm in &moved {
+            let gripper = paths[arm][paths[arm].len() - 1];
+            let (task, point) = &mut progress[arm];
+            let tasks = &submission.arms[arm].tasks;
+            while *task < tasks.len() && input.tasks[tasks[*task]].points[*point] == gripper {
+                *point += 1;
+                if *point == input.tasks[tasks[*task]].points.len() {
+                    *task += 1;
+                    *point = 0;
+                }
+            }
+        }
+    }
+
+    let mut total_score = 0;
+    let mut completed_tasks = 0;
+    let mut arms = Vec::with_capacity(submission.arms.len());
+    for (arm, plan) in submission.arms.iter().enumerate() {
+        let (task, point) = progress[arm];
+        if task < plan.tasks.len() {
+            return Err(ProblemError::with_details(
+                "unfinished-task",
+                format!(
+                    "Arm {arm} does not complete task {id} ({point} of {total} assembly points \
+                     visited)",
+                    id = plan.tasks[task],
+                    total = input.tasks[plan.tasks[task]].points.len()
+                ),
+                serde_json::json!({ "arm": arm, "task": plan.tasks[task], "visited_points": point }),
+            ));
+        }
+        let score: u64 = plan.tasks.iter().map(|&task| input.tasks[task].score).sum();
+        total_score += score;
+        completed_tasks += plan.tasks.len();
+        arms.push(ArmResult {
+            mount: plan.mount,
+            completed_tasks: plan.tasks.len(),
+            score,
+        });
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        completed_tasks,
+        arms,
+    })
+}
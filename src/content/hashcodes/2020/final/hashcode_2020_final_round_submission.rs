@@ -0,0 +1,97 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+use super::Cell;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Wait,
+}
+
+impl Instruction {
+    /// Change of `(x, y)` caused by the instruction.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Instruction::Up => (0, 1),
+            Instruction::Down => (0, -1),
+            Instruction::Left => (-1, 0),
+            Instruction::Right => (1, 0),
+            Instruction::Wait => (0, 0),
+        }
+    }
+}
+
+/// One installed robotic arm.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArmPlan {
+    pub mount: Cell,
+    /// Tasks in the order the arm works on them.
+    pub tasks: Vec<usize>,
+    pub instructions: Vec<Instruction>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub arms: Vec<ArmPlan>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-arm-count",
+            "Submission must start with the number of robotic arms",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-arm-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+
+    let mut arms = Vec::with_capacity(count.min(1 << 10));
+    for arm in 0..count {
+        let mount = (tokens.parse::<usize>("x")?, tokens.parse::<usize>("y")?);
+        let task_count = tokens.parse::<usize>("task_count")?;
+        let instruction_count = tokens.parse::<usize>("instruction_count")?;
+        let mut tasks = Vec::with_capacity(task_count.min(1 << 10));
+        for _ in 0..task_count {
+            tasks.push(tokens.parse::<usize>("task")?);
+        }
+        let mut instructions = Vec::with_capacity(instruction_count.min(1 << 16));
+        for _ in 0..instruction_count {
+            let instruction = match tokens.token("instruction")? {
+                "U" => Instruction::Up,
+                "D" => Instruction::Down,
+                "L" => Instruction::Left,
+                "R" => Instruction::Right,
+                "W" => Instruction::Wait,
+                other => {
+                    return Err(ProblemError::with_details(
+                        "invalid-instruction",
+                        format!(
+                            "Arm {arm} has unknown instruction '{other}' on line {line}",
+                            line = tokens.line()
+                        ),
+                        serde_json::json!({ "arm": arm, "value": other, "line": tokens.line() }),
+                    ))
+                }
+            };
+            instructions.push(instruction);
+        }
+        arms.push(ArmPlan {
+            mount,
+            tasks,
+            instructions,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { arms })
+}
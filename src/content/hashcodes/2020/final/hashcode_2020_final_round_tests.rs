@@ -0,0 +1,119 @@
+use super::submission::{parse_submission, Instruction};
+use super::HashCode2020Final;
+use crate::hashcodes::test_support::Fixture;
+
+const EXAMPLE_SUBMISSION: &str = "2
+1 1 1 5
+0
+U R W U R
+1 3 1 4
+2
+R R L L
+";
+
+fn example() -> Fixture<HashCode2020Final> {
+    Fixture::dataset(HashCode2020Final, "a_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    for dataset in super::input::DATASETS {
+        let parsed = Fixture::dataset(HashCode2020Final, dataset.name).input;
+        assert!(parsed.mount_points.len() >= parsed.arms, "{}", dataset.name);
+    }
+}
+
+#[test]
+fn submission_parser_reads_instructions() {
+    let submission = parse_submission(EXAMPLE_SUBMISSION).expect("parse");
+    assert_eq!(submission.arms[1].mount, (1, 3));
+    assert_eq!(submission.arms[1].tasks, vec![2]);
+    assert_eq!(submission.arms[0].instructions[2], Instruction::Wait);
+    let err = parse_submission("1\n1 1 1 1\n0\nX\n").expect_err("should fail");
+    assert_eq!(err.code, "invalid-instruction");
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    // In step 3 the first arm expands onto [2, 3] while the second retracts
+    // from it.
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 11);
+    assert_eq!(breakdown.completed_tasks, 2);
+    assert_eq!(breakdown.arms[0].score, 10);
+}
+
+#[test]
+fn consecutive_tasks_can_finish_on_the_same_step() {
+    let tasks = Fixture::inline(
+        HashCode2020Final,
+        "3 2 1 1 4 3\n0 1\n1 1 0 0\n1 2 0 0 1 0\n1 1 1 0\n1 2 1 0 2 0\n",
+    );
+    let breakdown = tasks.score("1\n0 1 4 3\n0 1 2 3\nD R R\n");
+    assert_eq!(breakdown.total_score, 4);
+}
+
+#[test]
+fn scoring_rejects_collisions() {
+    let example = example();
+    let err = example.score_error("2\n1 1 1 1\n0\nU\n1 3 1 1\n2\nD\n");
+    assert_eq!(err.code, "arm-collision");
+    assert_eq!(
+        err.details.expect("details")["arms"],
+        serde_json::json!([1, 0])
+    );
+    let err = example.score_error("1\n1 1 1 3\n0\nU R R\n");
+    assert_eq!(err.code, "mount-point-collision");
+    assert_eq!(err.details.expect("details")["step"], 2);
+    assert_eq!(
+        example.score_error("1\n1 3 1 1\n2\nU\n").code,
+        "out-of-bounds"
+    );
+
+    let grid = Fixture::inline(HashCode2020Final, "4 4 1 1 1 5\n0 0\n1 1\n3 3\n");
+    let err = grid.score_error("1\n0 0 1 5\n0\nR R U L D\n");
+    assert_eq!(err.code, "arm-collision");
+    assert_eq!(
+        err.details.expect("details")["arms"],
+        serde_json::json!([0, 0])
+    );
+}
+
+#[test]
+fn scoring_rejects_invalid_setup() {
+    let example = example();
+    assert_eq!(
+        example.score_error("1\n2 2 1 1\n0\nW\n").code,
+        "invalid-mount"
+    );
+    assert_eq!(
+        example
+            .score_error("2\n1 1 1 1\n0\nW\n1 1 1 1\n1\nW\n")
+            .code,
+        "duplicate-mount"
+    );
+    assert_eq!(
+        example
+            .score_error("2\n1 1 1 1\n0\nW\n1 3 1 1\n0\nW\n")
+            .code,
+        "duplicate-task"
+    );
+    assert_eq!(
+        example.score_error("1\n1 1 1 1\n3\nW\n").code,
+        "unknown-task"
+    );
+    assert_eq!(
+        example.score_error("1\n1 1 1 6\n0\nW W W W W W\n").code,
+        "too-many-instructions"
+    );
+    assert_eq!(
+        example
+            .score_error("3\n1 1 1 1\n0\nW\n1 3 1 1\n1\nW\n3 2 1 1\n2\nW\n")
+            .code,
+        "too-many-arms"
+    );
+    assert_eq!(
+        example.score_error("1\n1 1 1 1\n0\nU\n").code,
+        "unfinished-task"
+    );
+}
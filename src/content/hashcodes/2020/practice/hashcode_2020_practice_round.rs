@@ -0,0 +1,69 @@
+//! Google Hash Code 2020 Practice Round ("More pizza") implementation.
+
+#[path = "hashcode_2020_practice_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2020_practice_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2020_practice_round_solver.rs"]
+pub mod solver;
+#[path = "hashcode_2020_practice_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2020_practice_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2020 practice round.
+pub struct HashCode2020Practice;
+
+impl Problem for HashCode2020Practice {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2020_practice_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2020"
+    }
+
+    fn round(&self) -> &'static str {
+        "practice"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["practice_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+
+    fn solver(&self) -> Option<fn(&ProblemInput) -> String> {
+        Some(|input| submission::format_submission(&solver::solve(input)))
+    }
+}
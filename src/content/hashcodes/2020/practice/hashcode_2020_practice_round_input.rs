@@ -0,0 +1,61 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    /// Maximum number of slices to order.
+    pub max_slices: u64,
+    /// Number of slices in each pizza type.
+    pub slices: Vec<u64>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.in",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2020_practice_round.in/a_example.in"),
+    },
+    Dataset {
+        name: "b_small",
+        file: "b_small.in",
+        aliases: &["small"],
+        contents: include_str!("hashcode_2020_practice_round.in/b_small.in"),
+    },
+    Dataset {
+        name: "c_medium",
+        file: "c_medium.in",
+        aliases: &["medium"],
+        contents: include_str!("hashcode_2020_practice_round.in/c_medium.in"),
+    },
+    Dataset {
+        name: "d_quite_big",
+        file: "d_quite_big.in",
+        aliases: &["quite_big"],
+        contents: include_str!("hashcode_2020_practice_round.in/d_quite_big.in"),
+    },
+    Dataset {
+        name: "e_also_big",
+        file: "e_also_big.in",
+        aliases: &["also_big"],
+        contents: include_str!("hashcode_2020_practice_round.in/e_also_big.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2020", "practice", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let max_slices = tokens.parse::<u64>("max_slices")?;
+    let type_count = tokens.parse::<usize>("pizza_types")?;
+    let mut slices = Vec::with_capacity(type_count);
+    for _ in 0..type_count {
+        slices.push(tokens.parse::<u64>("slices")?);
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput { max_slices, slices })
+}
@@ -0,0 +1,86 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::solver::{optimum, Optimum};
+use super::submission::ProblemSubmission;
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub pizzas: usize,
+    pub max_slices: u64,
+    /// Best possible score, exact for small datasets.
+    pub optimum: Optimum,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let exact = matches!(self.optimum, Optimum::Exact(_));
+        serde_json::json!({
+            "total_score": self.total_score,
+            "pizzas": self.pizzas,
+            "max_slices": self.max_slices,
+            "optimum": self.optimum.value(),
+            "optimum_is_exact": exact,
+            "gap": self.optimum.value() - self.total_score,
+        })
+    }
+}
+
+/// Validates the order and computes the official score:
+/// - every pizza type exists and is ordered at most once
+/// - the ordered slices do not exceed the maximum `M`
+///
+/// The score is the total number of ordered slices.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let mut ordered = vec![false; input.slices.len()];
+    let mut total_score = 0;
+    for (index, &pizza) in submission.pizzas.iter().enumerate() {
+        if pizza >= input.slices.len() {
+            return Err(ProblemError::with_details(
+                "unknown-pizza",
+                format!(
+                    "Pizza type {pizza} does not exist; there are {count} types",
+                    count = input.slices.len()
+                ),
+                serde_json::json!({ "index": index, "pizza": pizza }),
+            ));
+        }
+        if ordered[pizza] {
+            return Err(ProblemError::with_details(
+                "duplicate-pizza",
+                format!("Pizza type {pizza} is ordered more than once"),
+                serde_json::json!({ "index": index, "pizza": pizza }),
+            ));
+        }
+        ordered[pizza] = true;
+        total_score += input.slices[pizza];
+    }
+
+    if total_score > input.max_slices {
+        return Err(ProblemError::with_details(
+            "too-many-slices",
+            format!(
+                "Order has {total_score} slices but at most {max} are allowed",
+                max = input.max_slices
+            ),
+            serde_json::json!({ "slices": total_score, "max_slices": input.max_slices }),
+        ));
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        pizzas: submission.pizzas.len(),
+        max_slices: input.max_slices,
+        optimum: optimum(input),
+    })
+}
@@ -0,0 +1,102 @@
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Largest amount of subset-sum work (pizza types times slice budget) the
+/// exact solver takes on; larger datasets fall back to bounds and greedy.
+const EXACT_WORK_LIMIT: u64 = 1 << 24;
+
+/// Best achievable slice total for a dataset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Optimum {
+    Exact(u64),
+    UpperBound(u64),
+}
+
+impl Optimum {
+    pub fn value(self) -> u64 {
+        match self {
+            Optimum::Exact(value) | Optimum::UpperBound(value) => value,
+        }
+    }
+}
+
+/// Computes the optimum exactly when the dataset is small enough, otherwise
+/// returns the trivial bound `min(M, total slices)`.
+pub fn optimum(input: &ProblemInput) -> Optimum {
+    let total: u64 = input.slices.iter().sum();
+    if total <= input.max_slices {
+        return Optimum::Exact(total);
+    }
+    match reachable_sums(input) {
+        Some(first) => Optimum::Exact(best_sum(&first)),
+        None => Optimum::UpperBound(input.max_slices),
+    }
+}
+
+/// Orders the best subset of pizzas for small datasets and fills the budget
+/// greedily from the largest pizza down for the big ones.
+pub fn solve(input: &ProblemInput) -> ProblemSubmission {
+    let total: u64 = input.slices.iter().sum();
+    let mut pizzas = if total <= input.max_slices {
+        (0..input.slices.len()).collect()
+    } else if let Some(first) = reachable_sums(input) {
+        let mut pizzas = Vec::new();
+        let mut sum = best_sum(&first);
+        while sum > 0 {
+            let pizza = first[sum as usize] as usize;
+            pizzas.push(pizza);
+            sum -= input.slices[pizza];
+        }
+        pizzas
+    } else {
+        let mut order: Vec<usize> = (0..input.slices.len()).collect();
+        order.sort_by_key(|&pizza| std::cmp::Reverse(input.slices[pizza]));
+        let mut remaining = input.max_slices;
+        order
+            .into_iter()
+            .filter(|&pizza| {
+                let fits = input.slices[pizza] <= remaining;
+                if fits {
+                    remaining -= input.slices[pizza];
+                }
+                fits
+            })
+            .collect()
+    };
+    pizzas.sort_unstable();
+    ProblemSubmission { pizzas }
+}
+
+const UNREACHABLE: u32 = u32::MAX;
+
+/// Subset-sum table: for every reachable total up to `M`, the pizza that
+/// first reached it. Following those pizzas back always ends at zero because
+/// each entry only builds on totals reached by earlier pizzas.
+fn reachable_sums(input: &ProblemInput) -> Option<Vec<u32>> {
+    let work = (input.max_slices + 1).checked_mul(input.slices.len() as u64)?;
+    if work > EXACT_WORK_LIMIT {
+        return None;
+    }
+    let limit = input.max_slices as usize;
+    let mut first = vec![UNREACHABLE; limit + 1];
+    first[0] = 0;
+    for (pizza, &slices) in input.slices.iter().enumerate() {
+        let slices = slices as usize;
+        if slices == 0 || slices > limit {
+            continue;
+        }
+        for sum in (slices..=limit).rev() {
+            if first[sum] == UNREACHABLE && first[sum - slices] != UNREACHABLE {
+                first[sum] = pizza as u32;
+            }
+        }
+    }
+    Some(first)
+}
+
+fn best_sum(first: &[u32]) -> u64 {
+    first
+        .iter()
+        .rposition(|&pizza| pizza != UNREACHABLE)
+        .unwrap_or(0) as u64
+}
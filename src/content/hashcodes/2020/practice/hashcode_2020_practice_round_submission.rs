@@ -0,0 +1,39 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Indices of the ordered pizza types.
+    pub pizzas: Vec<usize>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-pizza-count",
+            "Submission must start with the number of pizza types to order",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-pizza-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+
+    let mut pizzas = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        pizzas.push(tokens.parse::<usize>("pizza")?);
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { pizzas })
+}
+
+/// Renders a submission in the statement's output format.
+pub fn format_submission(submission: &ProblemSubmission) -> String {
+    let pizzas: Vec<String> = submission.pizzas.iter().map(usize::to_string).collect();
+    format!("{}\n{}\n", pizzas.len(), pizzas.join(" "))
+}
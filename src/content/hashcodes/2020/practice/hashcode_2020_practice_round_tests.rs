@@ -0,0 +1,98 @@
+use super::scorer::score;
+use super::solver::{optimum, solve, Optimum};
+use super::submission::parse_submission;
+use super::HashCode2020Practice;
+use crate::hashcodes::test_support::Fixture;
+
+fn example() -> Fixture<HashCode2020Practice> {
+    Fixture::dataset(HashCode2020Practice, "a_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2020Practice, "e_also_big").input;
+    assert_eq!(parsed.max_slices, 505_000_000);
+    assert_eq!(parsed.slices.len(), 10_000);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("3\n0 2 3\n");
+    assert_eq!(breakdown.total_score, 16);
+    assert_eq!(breakdown.optimum, Optimum::Exact(16));
+}
+
+#[test]
+fn optimum_is_exact_for_small_datasets_and_bounded_for_large_ones() {
+    let small = Fixture::dataset(HashCode2020Practice, "c_medium").input;
+    assert_eq!(optimum(&small), Optimum::Exact(4500));
+    let large = Fixture::dataset(HashCode2020Practice, "d_quite_big").input;
+    assert_eq!(optimum(&large), Optimum::UpperBound(1_000_000_000));
+}
+
+#[test]
+fn solver_produces_valid_orders() {
+    for name in ["a_example", "b_small", "c_medium", "d_quite_big"] {
+        let input = Fixture::dataset(HashCode2020Practice, name).input;
+        let breakdown = score(&input, &solve(&input)).expect("should score");
+        if let Optimum::Exact(best) = breakdown.optimum {
+            assert_eq!(breakdown.total_score, best, "{name}");
+        }
+    }
+}
+
+#[test]
+fn scoring_rejects_invalid_orders() {
+    let example = example();
+    assert_eq!(example.score_error("2\n0 4\n").code, "unknown-pizza");
+    assert_eq!(example.score_error("2\n1 1\n").code, "duplicate-pizza");
+    assert_eq!(example.score_error("3\n1 2 3\n").code, "too-many-slices");
+}
+
+#[test]
+fn submission_parser_detects_missing_pizzas() {
+    let err = parse_submission("3\n0 2\n").expect_err("should fail");
+    assert_eq!(err.code, "missing-value");
+}
+
+#[test]
+fn formatted_solution_parses_back() {
+    let input = example().input;
+    let solution = solve(&input);
+    let text = super::submission::format_submission(&solution);
+    assert_eq!(parse_submission(&text).expect("parse"), solution);
+}
+
+#[test]
+fn score_round_totals_datasets_and_zeroes_missing_ones() {
+    use crate::hashcodes::ProblemKey;
+
+    let key = ProblemKey::from_route("2020", "practice").expect("registered");
+    let round = key
+        .score_round(&[("example.in", "3\n0 2 3\n"), ("b_small", "1\n99\n")])
+        .expect("should score");
+    assert_eq!(round.total_score, 16);
+    assert_eq!(round.datasets.len(), 5);
+    assert_eq!(round.datasets[0].score, 16);
+    assert!(round.datasets[1].submitted);
+    assert!(round.datasets[1].error.is_some());
+    assert!(!round.datasets[2].submitted);
+    assert_eq!(round.datasets[2].score, 0);
+
+    let duplicate = key
+        .score_round(&[("a_example", "0\n\n"), ("example", "0\n\n")])
+        .expect_err("duplicate");
+    assert_eq!(duplicate.code, "duplicate-submission");
+    let round = key
+        .score_round(&[("a_example.out", "3\n0 2 3\n"), ("z_missing", "")])
+        .expect("should score");
+    assert_eq!(round.total_score, 16);
+    assert_eq!(round.datasets.len(), 6);
+    let unknown = &round.datasets[5];
+    assert_eq!(unknown.dataset, "z_missing");
+    assert_eq!(unknown.score, 0);
+    assert_eq!(
+        unknown.error.as_ref().map(|error| error.code),
+        Some("unknown-input-file")
+    );
+}
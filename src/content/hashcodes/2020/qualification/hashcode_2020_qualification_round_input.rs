@@ -0,0 +1,106 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Library {
+    pub books: Vec<usize>,
+    pub signup_days: u64,
+    pub books_per_day: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub book_scores: Vec<u64>,
+    pub libraries: Vec<Library>,
+    pub days: u64,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.txt",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2020_qualification_round.in/a_example.txt"),
+    },
+    Dataset {
+        name: "b_read_on",
+        file: "b_read_on.txt",
+        aliases: &["read_on"],
+        contents: include_str!("hashcode_2020_qualification_round.in/b_read_on.txt"),
+    },
+    Dataset {
+        name: "c_incunabula",
+        file: "c_incunabula.txt",
+        aliases: &["incunabula"],
+        contents: include_str!("hashcode_2020_qualification_round.in/c_incunabula.txt"),
+    },
+    Dataset {
+        name: "d_tough_choices",
+        file: "d_tough_choices.txt",
+        aliases: &["tough_choices"],
+        contents: include_str!("hashcode_2020_qualification_round.in/d_tough_choices.txt"),
+    },
+    Dataset {
+        name: "e_so_many_books",
+        file: "e_so_many_books.txt",
+        aliases: &["so_many_books"],
+        contents: include_str!("hashcode_2020_qualification_round.in/e_so_many_books.txt"),
+    },
+    Dataset {
+        name: "f_libraries_of_the_world",
+        file: "f_libraries_of_the_world.txt",
+        aliases: &["libraries_of_the_world"],
+        contents: include_str!("hashcode_2020_qualification_round.in/f_libraries_of_the_world.txt"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2020", "qualification", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let book_count = tokens.parse::<usize>("books")?;
+    let library_count = tokens.parse::<usize>("libraries")?;
+    let days = tokens.parse::<u64>("days")?;
+
+    let mut book_scores = Vec::with_capacity(book_count);
+    for _ in 0..book_count {
+        book_scores.push(tokens.parse::<u64>("book_score")?);
+    }
+
+    let mut libraries = Vec::with_capacity(library_count);
+    for _ in 0..library_count {
+        let count = tokens.parse::<usize>("library_books")?;
+        let signup_days = tokens.parse::<u64>("signup_days")?;
+        let books_per_day = tokens.parse::<u64>("books_per_day")?;
+        let mut books = Vec::with_capacity(count);
+        for _ in 0..count {
+            let book = tokens.parse::<usize>("book")?;
+            if book >= book_count {
+                return Err(ProblemError::with_details(
+                    "invalid-book",
+                    format!(
+                        "Library {index} lists unknown book {book}",
+                        index = libraries.len()
+                    ),
+                    serde_json::json!({ "library": libraries.len(), "book": book, "line": tokens.line() }),
+                ));
+            }
+            books.push(book);
+        }
+        libraries.push(Library {
+            books,
+            signup_days,
+            books_per_day,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        book_scores,
+        libraries,
+        days,
+    })
+}
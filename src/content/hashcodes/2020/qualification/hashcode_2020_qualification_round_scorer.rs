@@ -0,0 +1,156 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Timeline of one signed up library.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LibraryResult {
+    pub library: usize,
+    pub signup_start: u64,
+    pub signup_end: u64,
+    pub books_submitted: usize,
+    /// Books shipped before the deadline, including ones already scanned.
+    pub books_scanned: usize,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub distinct_books_scanned: usize,
+    pub libraries: Vec<LibraryResult>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let libraries: Vec<serde_json::Value> = self
+            .libraries
+            .iter()
+            .map(|result| {
+                serde_json::json!({
+                    "library": result.library,
+                    "signup_start": result.signup_start,
+                    "signup_end": result.signup_end,
+                    "books_submitted": result.books_submitted,
+                    "books_scanned": result.books_scanned,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "distinct_books_scanned": self.distinct_books_scanned,
+            "libraries": libraries,
+        })
+    }
+}
+
+/// Simulates the signups and computes the official score:
+/// - every library exists and is described at most once
+/// - a library only ships books it owns, each at most once
+///
+/// Libraries sign up one after another in submission order. Once its signup
+/// ends, a library ships up to its books-per-day every day until day `D`.
+/// The score is the sum of the scores of the distinct books scanned in time.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let book_count = input.book_scores.len();
+    let mut described = vec![false; input.libraries.len()];
+    // Index of the plan that last marked a book as owned / already shipped.
+    let mut owned_by = vec![usize::MAX; book_count];
+    let mut shipped_by = vec![usize::MAX; book_count];
+    let mut scanned = vec![false; book_count];
+
+    let mut day = 0u64;
+    let mut total_score = 0;
+    let mut distinct_books_scanned = 0;
+    let mut libraries = Vec::with_capacity(submission.libraries.len());
+    for (index, plan) in submission.libraries.iter().enumerate() {
+        let library = input.libraries.get(plan.library).ok_or_else(|| {
+            ProblemError::with_details(
+                "unknown-library",
+                format!(
+                    "Library {id} does not exist; there are {count} libraries",
+                    id = plan.library,
+                    count = input.libraries.len()
+                ),
+                serde_json::json!({ "index": index, "library": plan.library }),
+            )
+        })?;
+        if described[plan.library] {
+            return Err(ProblemError::with_details(
+                "duplicate-library",
+                format!(
+                    "Library {id} is described more than once",
+                    id = plan.library
+                ),
+                serde_json::json!({ "index": index, "library": plan.library }),
+            ));
+        }
+        described[plan.library] = true;
+
+        for &book in &library.books {
+            owned_by[book] = index;
+        }
+        for &book in &plan.books {
+            let details = serde_json::json!({ "library": plan.library, "book": book });
+            if book >= book_count || owned_by[book] != index {
+                return Err(ProblemError::with_details(
+                    "book-not-in-library",
+                    format!("Library {id} does not own book {book}", id = plan.library),
+                    details,
+                ));
+            }
+            if shipped_by[book] == index {
+                return Err(ProblemError::with_details(
+                    "duplicate-book",
+                    format!(
+                        "Library {id} ships book {book} more than once",
+                        id = plan.library
+                    ),
+                    details,
+                ));
+            }
+            shipped_by[book] = index;
+        }
+
+        let signup_start = day;
+        let signup_end = day + library.signup_days;
+        day = signup_end;
+        let capacity = input
+            .days
+            .saturating_sub(signup_end)
+            .saturating_mul(library.books_per_day);
+        let books_scanned = plan
+            .books
+            .len()
+            .min(capacity.min(usize::MAX as u64) as usize);
+        for &book in &plan.books[..books_scanned] {
+            if !scanned[book] {
+                scanned[book] = true;
+                distinct_books_scanned += 1;
+                total_score += input.book_scores[book];
+            }
+        }
+        libraries.push(LibraryResult {
+            library: plan.library,
+            signup_start,
+            signup_end,
+            books_submitted: plan.books.len(),
+            books_scanned,
+        });
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        distinct_books_scanned,
+        libraries,
+    })
+}
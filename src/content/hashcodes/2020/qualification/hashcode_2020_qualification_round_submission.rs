@@ -0,0 +1,45 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+/// A library in signup order with the books it ships, in shipping order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LibraryPlan {
+    pub library: usize,
+    pub books: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub libraries: Vec<LibraryPlan>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-library-count",
+            "Submission must start with the number of libraries to sign up",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-library-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+
+    let mut libraries = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        let library = tokens.parse::<usize>("library")?;
+        let book_count = tokens.parse::<usize>("book_count")?;
+        let mut books = Vec::with_capacity(book_count.min(1 << 20));
+        for _ in 0..book_count {
+            books.push(tokens.parse::<usize>("book")?);
+        }
+        libraries.push(LibraryPlan { library, books });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { libraries })
+}
@@ -0,0 +1,80 @@
+use super::scorer::LibraryResult;
+use super::submission::parse_submission;
+use super::HashCode2020Qualification;
+use crate::hashcodes::test_support::Fixture;
+
+const EXAMPLE_SUBMISSION: &str = "2\n1 3\n5 2 3\n0 5\n0 1 2 3 4\n";
+
+fn example() -> Fixture<HashCode2020Qualification> {
+    Fixture::dataset(HashCode2020Qualification, "a_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2020Qualification, "b_read_on").input;
+    assert_eq!(parsed.book_scores.len(), 100_000);
+    assert_eq!(parsed.libraries.len(), 100);
+    assert_eq!(parsed.days, 1000);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 16);
+    assert_eq!(breakdown.distinct_books_scanned, 5);
+    assert_eq!(
+        breakdown.libraries,
+        vec![
+            LibraryResult {
+                library: 1,
+                signup_start: 0,
+                signup_end: 3,
+                books_submitted: 3,
+                books_scanned: 3,
+            },
+            LibraryResult {
+                library: 0,
+                signup_start: 3,
+                signup_end: 5,
+                books_submitted: 5,
+                books_scanned: 4,
+            },
+        ]
+    );
+}
+
+#[test]
+fn libraries_signed_up_after_the_deadline_scan_nothing() {
+    let breakdown = example().score("2\n0 1\n0\n1 1\n5\n");
+    // Library 0 signs up in days 0-1, library 1 in days 2-4; both ship in time.
+    assert_eq!(breakdown.total_score, 1 + 4);
+    let late = Fixture::inline(HashCode2020Qualification, "1 2 3\n7\n1 2 1\n0\n1 2 1\n0\n");
+    let breakdown = late.score("2\n0 1\n0\n1 1\n0\n");
+    assert_eq!(breakdown.total_score, 7);
+    assert_eq!(breakdown.libraries[1].books_scanned, 0);
+}
+
+#[test]
+fn scoring_rejects_invalid_libraries() {
+    let example = example();
+    assert_eq!(example.score_error("1\n2 1\n0\n").code, "unknown-library");
+    assert_eq!(
+        example.score_error("2\n0 1\n0\n0 1\n1\n").code,
+        "duplicate-library"
+    );
+    assert_eq!(
+        example.score_error("1\n1 1\n1\n").code,
+        "book-not-in-library"
+    );
+    assert_eq!(example.score_error("1\n0 2\n1 1\n").code, "duplicate-book");
+    assert_eq!(
+        example.score_error("1\n0 1\n9\n").code,
+        "book-not-in-library"
+    );
+}
+
+#[test]
+fn submission_parser_requires_every_book() {
+    let err = parse_submission("1\n0 3\n1 2\n").expect_err("should fail");
+    assert_eq!(err.code, "missing-value");
+}
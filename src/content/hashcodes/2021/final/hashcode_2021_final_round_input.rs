@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Service {
+    pub name: String,
+    /// Binary the service runs in before any work starts.
+    pub binary: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Feature {
+    pub name: String,
+    /// Indices into `ProblemInput::services`, sorted and without repetitions.
+    pub services: Vec<usize>,
+    pub difficulty: u64,
+    pub daily_users: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub time_limit: u64,
+    pub engineers: usize,
+    pub binaries: usize,
+    /// Days an engineer needs to create a new binary.
+    pub new_binary_days: u64,
+    pub services: Vec<Service>,
+    pub features: Vec<Feature>,
+    /// Maps a service name to its index in `services`.
+    pub service_index: HashMap<String, usize>,
+    /// Maps a feature name to its index in `features`.
+    pub feature_index: HashMap<String, usize>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.in.txt",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2021_final_round.in/a_example.in.txt"),
+    },
+    Dataset {
+        name: "b_breadth_of_choice",
+        file: "b_breadth_of_choice.in.txt",
+        aliases: &["breadth_of_choice"],
+        contents: include_str!("hashcode_2021_final_round.in/b_breadth_of_choice.in.txt"),
+    },
+    Dataset {
+        name: "c_constrained_optimisation",
+        file: "c_constrained_optimisation.in.txt",
+        aliases: &["constrained_optimisation"],
+        contents: include_str!("hashcode_2021_final_round.in/c_constrained_optimisation.in.txt"),
+    },
+    Dataset {
+        name: "d_distinction",
+        file: "d_distinction.in.txt",
+        aliases: &["distinction"],
+        contents: include_str!("hashcode_2021_final_round.in/d_distinction.in.txt"),
+    },
+    Dataset {
+        name: "e_expectation_maximisation",
+        file: "e_expectation_maximisation.in.txt",
+        aliases: &["expectation_maximisation"],
+        contents: include_str!("hashcode_2021_final_round.in/e_expectation_maximisation.in.txt"),
+    },
+    Dataset {
+        name: "f_five_thousand",
+        file: "f_five_thousand.in.txt",
+        aliases: &["five_thousand"],
+        contents: include_str!("hashcode_2021_final_round.in/f_five_thousand.in.txt"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2021", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let time_limit = tokens.parse::<u64>("time_limit")?;
+    let engineers = tokens.parse::<usize>("engineers")?;
+    let service_count = tokens.parse::<usize>("services")?;
+    let binaries = tokens.parse::<usize>("binaries")?;
+    let feature_count = tokens.parse::<usize>("features")?;
+    let new_binary_days = tokens.parse::<u64>("new_binary_days")?;
+
+    let mut services = Vec::with_capacity(service_count);
+    let mut service_index = HashMap::with_capacity(service_count);
+    for index in 0..service_count {
+        let name = tokens.token("service_name")?;
+        let binary = tokens.parse::<usize>("service_binary")?;
+        if binary >= binaries {
+            return Err(ProblemError::with_details(
+                "invalid-binary",
+                format!("Service '{name}' runs in unknown binary {binary}"),
+                serde_json::json!({ "service": name, "binary": binary, "line": tokens.line() }),
+            ));
+        }
+        service_index.insert(name.to_string(), index);
+        services.push(Service {
+            name: name.to_string(),
+            binary,
+        });
+    }
+
+    let mut features = Vec::with_capacity(feature_count);
+    let mut feature_index = HashMap::with_capacity(feature_count);
+    for index in 0..feature_count {
+        let name = tokens.token("feature_name")?;
+        let count = tokens.parse::<usize>("feature_services")?;
+        let difficulty = tokens.parse::<u64>("feature_difficulty")?;
+        let daily_users = tokens.parse::<u64>("feature_users")?;
+        let mut needed = Vec::with_capacity(count);
+        for _ in 0..count {
+            let service = tokens.token("feature_service")?;
+            let service = service_index.get(service).copied().ok_or_else(|| {
+                ProblemError::with_details(
+                    "invalid-service",
+                    format!("Feature '{name}' relies on unknown service '{service}'"),
+                    serde_json::json!({
+                        "feature": name,
+                        "service": service,
+                        "line": tokens.line(),
+                    }),
+                )
+            })?;
+            needed.push(service);
+        }
+        needed.sort_unstable();
+        needed.dedup();
+        feature_index.insert(name.to_string(), index);
+        features.push(Feature {
+            name: name.to_string(),
+            services: needed,
+            difficulty,
+            daily_users,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        time_limit,
+        engineers,
+        binaries,
+        new_binary_days,
+        services,
+        features,
+        service_index,
+        feature_index,
+    })
+}
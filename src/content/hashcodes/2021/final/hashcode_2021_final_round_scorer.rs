@@ -0,0 +1,381 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::{Job, ProblemSubmission};
+
+/// Launch day and score of one feature that went live before the time limit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LaunchedFeature {
+    pub feature: String,
+    pub launched_on: u64,
+    pub score: u64,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    /// Features launched before the time limit, in launch order.
+    pub launched_features: Vec<LaunchedFeature>,
+    /// Features implemented in some but not all of their services.
+    pub partially_implemented: usize,
+    pub binaries_created: usize,
+    pub services_moved: usize,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let launched: Vec<serde_json::Value> = self
+            .launched_features
+            .iter()
+            .map(|launch| {
+                serde_json::json!({
+                    "feature": launch.feature,
+                    "launched_on": launch.launched_on,
+                    "score": launch.score,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "launched_features": launched,
+            "partially_implemented": self.partially_implemented,
+            "binaries_created": self.binaries_created,
+            "services_moved": self.services_moved,
+        })
+    }
+}
+
+/// A job with its feature or service name resolved against the input.
+#[derive(Clone, Copy)]
+enum Task {
+    Implement { feature: usize, binary: usize },
+    Move { service: usize, binary: usize },
+    New,
+    Wait { days: u64 },
+}
+
+/// What an engineer is busy with until their current task finishes.
+enum Active {
+    Implement {
+        feature: usize,
+        binary: usize,
+        services: Vec<usize>,
+    },
+    Move {
+        from: usize,
+        to: usize,
+    },
+    New {
+        binary: usize,
+    },
+    Wait,
+}
+
+/// Event phases: tasks finishing on a day release their binaries before any
+/// task starts that day.
+const FINISH: u8 = 0;
+const START: u8 = 1;
+
+/// Simulates every engineer's job list day by day and computes the official
+/// score:
+/// - at most `G` engineers are planned and every job names a known feature
+///   or service and an existing binary
+/// - `impl` takes `D + R + C` days, where `R` counts the services in the
+///   binary and `C` the engineers already working on features there on the
+///   first day of the job: earlier `impl`s still running, including those
+///   started the same day, but not those finishing that day; the same
+///   feature is implemented by one engineer at a time per binary
+/// - `move` takes the larger service count of both binaries, targets another
+///   binary and needs both to be free of any other work
+/// - `new` takes `N` days and creates the binary with the next unused ID,
+///   which cannot be used until it exists
+///
+/// Engineers start their jobs back to back from day 0; on a shared start day
+/// the engineer listed first goes first. Jobs starting at or after `L` are
+/// ignored. A feature launches once it is implemented in all of its services
+/// and, launched on day `I < L`, scores `users * (L - I)`.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    if submission.engineers.len() > input.engineers {
+        return Err(ProblemError::with_details(
+            "too-many-engineers",
+            format!(
+                "Submission plans {planned} engineers but only {available} are available",
+                planned = submission.engineers.len(),
+                available = input.engineers
+            ),
+            serde_json::json!({
+                "planned": submission.engineers.len(),
+                "available": input.engineers,
+            }),
+        ));
+    }
+
+    let mut plans: Vec<Vec<Task>> = Vec::with_capacity(submission.engineers.len());
+    for (engineer, jobs) in submission.engineers.iter().enumerate() {
+        let mut tasks = Vec::with_capacity(jobs.len());
+        for (index, scheduled) in jobs.iter().enumerate() {
+            let unknown = |code: &'static str, kind: &str, name: &str| {
+                ProblemError::with_details(
+                    code,
+                    format!("Engineer {engineer}, job {index}: {kind} '{name}' does not exist"),
+                    serde_json::json!({
+                        "engineer": engineer,
+                        "job": index,
+                        "line": scheduled.line,
+                        "name": name,
+                    }),
+                )
+            };
+            let task = match &scheduled.job {
+                Job::Implement { feature, binary } => Task::Implement {
+                    feature: input
+                        .feature_index
+                        .get(feature)
+                        .copied()
+                        .ok_or_else(|| unknown("unknown-feature", "feature", feature))?,
+                    binary: *binary,
+                },
+                Job::Move { service, binary } => Task::Move {
+                    service: input
+                        .service_index
+                        .get(service)
+                        .copied()
+                        .ok_or_else(|| unknown("unknown-service", "service", service))?,
+                    binary: *binary,
+                },
+                Job::New => Task::New,
+                Job::Wait { days } => Task::Wait { days: *days },
+            };
+            tasks.push(task);
+        }
+        plans.push(tasks);
+    }
+
+    let limit = input.time_limit;
+    let mut binary_of: Vec<usize> = input
+        .services
+        .iter()
+        .map(|service| service.binary)
+        .collect();
+    let mut members: Vec<Vec<usize>> = vec![Vec::new(); input.binaries];
+    let mut slot = vec![0usize; input.services.len()];
+    for (service, &binary) in binary_of.iter().enumerate() {
+        slot[service] = members[binary].len();
+        members[binary].push(service);
+    }
+    let mut implementers = vec![0u64; input.binaries];
+    let mut locked = vec![false; input.binaries];
+    let mut in_progress: HashSet<(usize, usize)> = HashSet::new();
+
+    let mut implemented: Vec<Vec<bool>> = input
+        .features
+        .iter()
+        .map(|feature| vec![false; feature.services.len()])
+        .collect();
+    let mut remaining: Vec<usize> = input.features.iter().map(|f| f.services.len()).collect();
+    let mut launched_features = Vec::new();
+    let mut binaries_created = 0;
+    let mut services_moved = 0;
+
+    let mut next_job = vec![0usize; plans.len()];
+    let mut active: Vec<Option<Active>> = (0..plans.len()).map(|_| None).collect();
+    let mut events = BinaryHeap::new();
+    for (engineer, tasks) in plans.iter().enumerate() {
+        if !tasks.is_empty() && limit > 0 {
+            events.push(Reverse((0u64, START, engineer)));
+        }
+    }
+
+    while let Some(Reverse((day, phase, engineer))) = events.pop() {
+        if phase == FINISH {
+            match active[engineer].take() {
+                Some(Active::Implement {
+                    feature,
+                    binary,
+                    services,
+                }) => {
+                    implementers[binary] -= 1;
+                    in_progress.remove(&(feature, binary));
+                    let needed = &input.features[feature].services;
+                    for service in services {
+                        let position = needed
+                            .binary_search(&service)
+                            .expect("only relevant services are recorded");
+                        if !implemented[feature][position] {
+                            implemented[feature][position] = true;
+                            remaining[feature] -= 1;
+                            if remaining[feature] == 0 {
+                                let users = input.features[feature].daily_users;
+                                launched_features.push(LaunchedFeature {
+                                    feature: input.features[feature].name.clone(),
+                                    launched_on: day,
+                                    score: users * (limit - day),
+                                });
+                            }
+                        }
+                    }
+                }
+                Some(Active::Move { from, to }) => {
+                    locked[from] = false;
+                    locked[to] = false;
+                }
+                Some(Active::New { binary }) => locked[binary] = false,
+                Some(Active::Wait) | None => {}
+            }
+            continue;
+        }
+
+        let index = next_job[engineer];
+        next_job[engineer] += 1;
+        let line = submission.engineers[engineer][index].line;
+        let fail = |code: &'static str, message: String, binary: usize| {
+            ProblemError::with_details(
+                code,
+                format!("Engineer {engineer}, job {index} on day {day}: {message}"),
+                serde_json::json!({
+                    "engineer": engineer,
+                    "job": index,
+                    "line": line,
+                    "day": day,
+                    "binary": binary,
+                }),
+            )
+        };
+        let binary_count = members.len();
+        let (duration, task) = match plans[engineer][index] {
+            Task::Implement { feature, binary } => {
+                if binary >= binary_count {
+                    return Err(fail(
+                        "unknown-binary",
+                        format!("binary {binary} does not exist"),
+                        binary,
+                    ));
+                }
+                if locked[binary] {
+                    return Err(fail(
+                        "binary-busy",
+                        format!("binary {binary} is being created or has a service moving"),
+                        binary,
+                    ));
+                }
+                if !in_progress.insert((feature, binary)) {
+                    return Err(fail(
+                        "feature-in-progress",
+                        format!(
+                            "feature '{name}' is already being implemented in binary {binary}",
+                            name = input.features[feature].name
+                        ),
+                        binary,
+                    ));
+                }
+                let needed = &input.features[feature].services;
+                let services: Vec<usize> = members[binary]
+                    .iter()
+                    .copied()
+                    .filter(|service| needed.binary_search(service).is_ok())
+                    .collect();
+                let duration = input.features[feature].difficulty
+                    + members[binary].len() as u64
+                    + implementers[binary];
+                implementers[binary] += 1;
+                (
+                    duration,
+                    Active::Implement {
+                        feature,
+                        binary,
+                        services,
+                    },
+                )
+            }
+            Task::Move { service, binary } => {
+                let from = binary_of[service];
+                if binary >= binary_count {
+                    return Err(fail(
+                        "unknown-binary",
+                        format!("binary {binary} does not exist"),
+                        binary,
+                    ));
+                }
+                if binary == from {
+                    return Err(fail(
+                        "same-binary",
+                        format!(
+                            "service '{name}' already runs in binary {binary}",
+                            name = input.services[service].name
+                        ),
+                        binary,
+                    ));
+                }
+                for busy in [from, binary] {
+                    if locked[busy] || implementers[busy] > 0 {
+                        return Err(fail(
+                            "binary-busy",
+                            format!("another engineer is still working on binary {busy}"),
+                            busy,
+                        ));
+                    }
+                }
+                let duration = members[from].len().max(members[binary].len()) as u64;
+                let position = slot[service];
+                members[from].swap_remove(position);
+                if let Some(&moved) = members[from].get(position) {
+                    slot[moved] = position;
+                }
+                slot[service] = members[binary].len();
+                members[binary].push(service);
+                binary_of[service] = binary;
+                locked[from] = true;
+                locked[binary] = true;
+                services_moved += 1;
+                (duration, Active::Move { from, to: binary })
+            }
+            Task::New => {
+                let binary = members.len();
+                members.push(Vec::new());
+                implementers.push(0);
+                locked.push(true);
+                binaries_created += 1;
+                (input.new_binary_days, Active::New { binary })
+            }
+            Task::Wait { days } => (days, Active::Wait),
+        };
+
+        let end = day.saturating_add(duration);
+        if end < limit {
+            active[engineer] = Some(task);
+            events.push(Reverse((end, FINISH, engineer)));
+            if next_job[engineer] < plans[engineer].len() {
+                events.push(Reverse((end, START, engineer)));
+            }
+        }
+    }
+
+    let partially_implemented = input
+        .features
+        .iter()
+        .enumerate()
+        .filter(|&(feature, spec)| {
+            remaining[feature] > 0 && remaining[feature] < spec.services.len()
+        })
+        .count();
+
+    Ok(ScoreBreakdown {
+        total_score: launched_features.iter().map(|launch| launch.score).sum(),
+        launched_features,
+        partially_implemented,
+        binaries_created,
+        services_moved,
+    })
+}
@@ -0,0 +1,153 @@
+use crate::hashcodes::error::ProblemError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Job {
+    /// `impl <feature> <binary>`
+    Implement { feature: String, binary: usize },
+    /// `move <service> <binary>`
+    Move { service: String, binary: usize },
+    /// `new`
+    New,
+    /// `wait <days>`
+    Wait { days: u64 },
+}
+
+/// A job together with the submission line it was read from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledJob {
+    pub job: Job,
+    pub line: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Job list of every planned engineer, in submission order.
+    pub engineers: Vec<Vec<ScheduledJob>>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut lines = raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or_else(|| {
+        ProblemError::new(
+            "missing-engineer-count",
+            "Submission must start with the number of engineers",
+        )
+    })?;
+    let engineer_count = header.trim().parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-engineer-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": header.trim() }),
+        )
+    })?;
+
+    let mut engineers = Vec::with_capacity(engineer_count.min(1 << 16));
+    for engineer in 0..engineer_count {
+        let (index, line) = lines.next().ok_or_else(|| {
+            ProblemError::with_details(
+                "engineer-count-mismatch",
+                format!("Submission declares {engineer_count} engineers but provided {engineer}"),
+                serde_json::json!({ "declared": engineer_count, "actual": engineer }),
+            )
+        })?;
+        let job_count = line.trim().parse::<usize>().map_err(|_err| {
+            ProblemError::with_details(
+                "invalid-job-count",
+                format!(
+                    "Line {line_no} must hold the job count of engineer {engineer}",
+                    line_no = index + 1
+                ),
+                serde_json::json!({
+                    "engineer": engineer,
+                    "line": index + 1,
+                    "value": line.trim(),
+                }),
+            )
+        })?;
+
+        let mut jobs = Vec::with_capacity(job_count.min(1 << 16));
+        for job in 0..job_count {
+            let (index, line) = lines.next().ok_or_else(|| {
+                ProblemError::with_details(
+                    "job-count-mismatch",
+                    format!("Engineer {engineer} declares {job_count} jobs but provided {job}"),
+                    serde_json::json!({
+                        "engineer": engineer,
+                        "declared": job_count,
+                        "actual": job,
+                    }),
+                )
+            })?;
+            jobs.push(ScheduledJob {
+                job: parse_job(line, index + 1, engineer, job)?,
+                line: index + 1,
+            });
+        }
+        engineers.push(jobs);
+    }
+
+    if let Some((index, line)) = lines.next() {
+        return Err(ProblemError::with_details(
+            "trailing-data",
+            format!(
+                "Unexpected extra line {line_no} after the last engineer",
+                line_no = index + 1
+            ),
+            serde_json::json!({ "line": index + 1, "content": line }),
+        ));
+    }
+
+    Ok(ProblemSubmission { engineers })
+}
+
+fn parse_job(line: &str, line_no: usize, engineer: usize, job: usize) -> Result<Job, ProblemError> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let number = |position: usize| -> Result<u64, ProblemError> {
+        let value = parts[position];
+        value.parse::<u64>().map_err(|_err| {
+            ProblemError::with_details(
+                "invalid-number",
+                format!("Value '{value}' on line {line_no} is not a non-negative integer"),
+                serde_json::json!({
+                    "engineer": engineer,
+                    "job": job,
+                    "line": line_no,
+                    "value": value,
+                }),
+            )
+        })
+    };
+
+    match parts.as_slice() {
+        ["impl", feature, _] => Ok(Job::Implement {
+            feature: feature.to_string(),
+            binary: number(2)? as usize,
+        }),
+        ["move", service, _] => Ok(Job::Move {
+            service: service.to_string(),
+            binary: number(2)? as usize,
+        }),
+        ["new"] => Ok(Job::New),
+        ["wait", _] => match number(1)? {
+            0 => Err(ProblemError::with_details(
+                "invalid-wait",
+                format!("Engineer {engineer} must wait at least one day on line {line_no}"),
+                serde_json::json!({ "engineer": engineer, "job": job, "line": line_no }),
+            )),
+            days => Ok(Job::Wait { days }),
+        },
+        _ => Err(ProblemError::with_details(
+            "invalid-job",
+            format!("Line {line_no} is not a valid impl, move, new or wait job"),
+            serde_json::json!({
+                "engineer": engineer,
+                "job": job,
+                "line": line_no,
+                "content": line,
+            }),
+        )),
+    }
+}
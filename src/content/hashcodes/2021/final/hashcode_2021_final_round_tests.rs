@@ -0,0 +1,132 @@
+use super::submission::parse_submission;
+use super::HashCode2021Final;
+use crate::hashcodes::test_support::Fixture;
+
+const EXAMPLE_SUBMISSION: &str = "2
+2
+move sc 2
+impl foo 2
+3
+wait 2
+impl bar 1
+impl bar 2
+";
+
+fn example() -> Fixture<HashCode2021Final> {
+    Fixture::dataset(HashCode2021Final, "a_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2021Final, "e_expectation_maximisation").input;
+    assert_eq!(parsed.engineers, 1000);
+    assert_eq!(parsed.services.len(), 200);
+    assert_eq!(parsed.features.len(), 100);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 220);
+    let launches: Vec<(&str, u64)> = breakdown
+        .launched_features
+        .iter()
+        .map(|launch| (launch.feature.as_str(), launch.launched_on))
+        .collect();
+    assert_eq!(launches, vec![("foo", 8), ("bar", 9)]);
+    assert_eq!(breakdown.services_moved, 1);
+}
+
+#[test]
+fn engineers_on_the_same_binary_slow_each_other_down() {
+    // Both start on binary 1 (sa, sc) on day 0: bar takes 1 + 2 + 0 days and
+    // foo, listed second, takes 3 + 2 + 1 but still misses sb and sd.
+    let breakdown = example().score("2\n1\nimpl bar 1\n1\nimpl foo 1\n");
+    assert_eq!(breakdown.total_score, 20 * 7);
+    assert_eq!(breakdown.launched_features[0].launched_on, 3);
+    assert_eq!(breakdown.partially_implemented, 1);
+}
+
+#[test]
+fn implementations_still_running_on_the_first_day_count() {
+    let example = example();
+    // foo runs on binary 1 (sa, sc) from day 0 to day 4. bar starting there
+    // on day 1 counts it, as the statement's C is every engineer working on
+    // the binary on the first day of the job: 1 + 2 + 1 days, launch on day 5.
+    let breakdown = example.score("2\n1\nimpl foo 1\n2\nwait 1\nimpl bar 1\n");
+    assert_eq!(breakdown.launched_features[0].launched_on, 5);
+    assert_eq!(breakdown.total_score, 20 * 5);
+
+    // Starting on day 5, once foo has finished, bar runs alone: 1 + 2 + 0.
+    let breakdown = example.score("2\n1\nimpl foo 1\n2\nwait 5\nimpl bar 1\n");
+    assert_eq!(breakdown.launched_features[0].launched_on, 8);
+    assert_eq!(breakdown.total_score, 20 * 2);
+}
+
+#[test]
+fn new_binaries_become_usable_once_created() {
+    let example = example();
+    // Binary 3 exists after 5 days; moving se there then takes one day.
+    let breakdown = example.score("1\n2\nnew\nmove se 3\n");
+    assert_eq!(breakdown.binaries_created, 1);
+    assert_eq!(breakdown.services_moved, 1);
+
+    let error = example.score_error("2\n1\nnew\n2\nwait 1\nmove se 3\n");
+    assert_eq!(error.code, "binary-busy");
+}
+
+#[test]
+fn scoring_rejects_invalid_jobs() {
+    let example = example();
+    let error = example.score_error("2\n1\nimpl foo 2\n1\nmove sc 2\n");
+    assert_eq!(error.code, "binary-busy");
+    let details = error.details.expect("details");
+    assert_eq!(details["engineer"], 1);
+    assert_eq!(details["job"], 0);
+
+    assert_eq!(
+        example
+            .score_error("2\n1\nimpl foo 2\n1\nimpl foo 2\n")
+            .code,
+        "feature-in-progress"
+    );
+    assert_eq!(
+        example.score_error("1\n1\nimpl baz 1\n").code,
+        "unknown-feature"
+    );
+    assert_eq!(
+        example.score_error("1\n1\nmove sz 1\n").code,
+        "unknown-service"
+    );
+    assert_eq!(
+        example.score_error("1\n1\nimpl foo 3\n").code,
+        "unknown-binary"
+    );
+    assert_eq!(example.score_error("1\n1\nmove sa 1\n").code, "same-binary");
+    assert_eq!(
+        example.score_error("3\n0\n0\n0\n").code,
+        "too-many-engineers"
+    );
+}
+
+#[test]
+fn submission_parser_reports_malformed_jobs() {
+    assert_eq!(
+        parse_submission("1\n1\nbuild foo 1\n")
+            .expect_err("should fail")
+            .code,
+        "invalid-job"
+    );
+    assert_eq!(
+        parse_submission("1\n1\nwait 0\n")
+            .expect_err("should fail")
+            .code,
+        "invalid-wait"
+    );
+    assert_eq!(
+        parse_submission("1\n2\nnew\n")
+            .expect_err("should fail")
+            .code,
+        "job-count-mismatch"
+    );
+}
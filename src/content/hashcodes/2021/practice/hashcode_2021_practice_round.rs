@@ -0,0 +1,63 @@
+//! Google Hash Code 2021 Practice Round ("Even more pizza") implementation.
+
+#[path = "hashcode_2021_practice_round_input.rs"]
+pub mod input;
+#[path = "hashcode_2021_practice_round_scorer.rs"]
+pub mod scorer;
+#[path = "hashcode_2021_practice_round_submission.rs"]
+pub mod submission;
+
+#[cfg(test)]
+#[path = "hashcode_2021_practice_round_tests.rs"]
+mod tests;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::{Dataset, Problem};
+use input::{ProblemInput, DATASETS};
+use scorer::ScoreBreakdown;
+use submission::ProblemSubmission;
+
+/// Registry entry for the 2021 practice round.
+pub struct HashCode2021Practice;
+
+impl Problem for HashCode2021Practice {
+    type Input = ProblemInput;
+    type Submission = ProblemSubmission;
+    type Breakdown = ScoreBreakdown;
+
+    fn id(&self) -> &'static str {
+        "hashcode_2021_practice_round"
+    }
+
+    fn year(&self) -> &'static str {
+        "2021"
+    }
+
+    fn round(&self) -> &'static str {
+        "practice"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["practice_round"]
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        DATASETS
+    }
+
+    fn parse_input(&self, raw: &str) -> Result<ProblemInput, ProblemError> {
+        input::parse_input(raw)
+    }
+
+    fn parse_submission(&self, raw: &str) -> Result<ProblemSubmission, ProblemError> {
+        submission::parse_submission(raw)
+    }
+
+    fn score(
+        &self,
+        input: &ProblemInput,
+        submission: &ProblemSubmission,
+    ) -> Result<ScoreBreakdown, ProblemError> {
+        scorer::score(input, submission)
+    }
+}
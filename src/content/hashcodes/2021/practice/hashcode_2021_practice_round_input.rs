@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    /// Interned ingredient ids of every pizza, sorted and without repetitions.
+    pub pizzas: Vec<Vec<u32>>,
+    /// Number of teams of 2, 3 and 4 people.
+    pub teams: [usize; 3],
+    /// Number of distinct ingredients across all pizzas.
+    pub ingredient_count: usize,
+}
+
+impl ProblemInput {
+    /// Number of teams with `size` people, or zero for unsupported sizes.
+    pub fn teams_of(&self, size: usize) -> usize {
+        match size {
+            2..=4 => self.teams[size - 2],
+            _ => 0,
+        }
+    }
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2021_practice_round.in/a_example"),
+    },
+    Dataset {
+        name: "b_little_bit_of_everything",
+        file: "b_little_bit_of_everything.in",
+        aliases: &["little_bit_of_everything"],
+        contents: include_str!("hashcode_2021_practice_round.in/b_little_bit_of_everything.in"),
+    },
+    Dataset {
+        name: "c_many_ingredients",
+        file: "c_many_ingredients.in",
+        aliases: &["many_ingredients"],
+        contents: include_str!("hashcode_2021_practice_round.in/c_many_ingredients.in"),
+    },
+    Dataset {
+        name: "d_many_pizzas",
+        file: "d_many_pizzas.in",
+        aliases: &["many_pizzas"],
+        contents: include_str!("hashcode_2021_practice_round.in/d_many_pizzas.in"),
+    },
+    Dataset {
+        name: "e_many_teams",
+        file: "e_many_teams.in",
+        aliases: &["many_teams"],
+        contents: include_str!("hashcode_2021_practice_round.in/e_many_teams.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2021", "practice", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let pizza_count = tokens.parse::<usize>("pizzas")?;
+    let teams = [
+        tokens.parse::<usize>("two_person_teams")?,
+        tokens.parse::<usize>("three_person_teams")?,
+        tokens.parse::<usize>("four_person_teams")?,
+    ];
+
+    let mut interned: HashMap<&str, u32> = HashMap::new();
+    let mut pizzas = Vec::with_capacity(pizza_count);
+    for _ in 0..pizza_count {
+        let ingredient_count = tokens.parse::<usize>("ingredient_count")?;
+        let mut ingredients = Vec::with_capacity(ingredient_count);
+        for _ in 0..ingredient_count {
+            let ingredient = tokens.token("ingredient")?;
+            let next_id = interned.len() as u32;
+            ingredients.push(*interned.entry(ingredient).or_insert(next_id));
+        }
+        ingredients.sort_unstable();
+        ingredients.dedup();
+        pizzas.push(ingredients);
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        pizzas,
+        teams,
+        ingredient_count: interned.len(),
+    })
+}
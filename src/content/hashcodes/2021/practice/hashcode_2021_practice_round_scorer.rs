@@ -0,0 +1,111 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    /// Deliveries made to teams of 2, 3 and 4 people.
+    pub deliveries: [usize; 3],
+    pub pizzas_delivered: usize,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "total_score": self.total_score,
+            "deliveries": {
+                "2": self.deliveries[0],
+                "3": self.deliveries[1],
+                "4": self.deliveries[2],
+            },
+            "pizzas_delivered": self.pizzas_delivered,
+        })
+    }
+}
+
+/// Validates the deliveries and computes the official score:
+/// - at most `T2`, `T3` and `T4` deliveries go to teams of 2, 3 and 4 people
+/// - every team receives exactly one pizza per member
+/// - every pizza exists and is delivered at most once
+///
+/// Each delivery scores the square of the number of distinct ingredients
+/// across its pizzas.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let mut deliveries = [0usize; 3];
+    let mut delivered = vec![false; input.pizzas.len()];
+    // Delivery that last counted each ingredient.
+    let mut counted = vec![usize::MAX; input.ingredient_count];
+    let mut total_score = 0;
+    let mut pizzas_delivered = 0;
+
+    for (delivery, pizzas) in submission.deliveries.iter().enumerate() {
+        let size = pizzas.len();
+        if !(2..=4).contains(&size) {
+            return Err(ProblemError::with_details(
+                "invalid-team-size",
+                format!("Delivery {delivery} is for a team of {size}; teams have 2 to 4 people"),
+                serde_json::json!({ "delivery": delivery, "team_size": size }),
+            ));
+        }
+        deliveries[size - 2] += 1;
+        if deliveries[size - 2] > input.teams_of(size) {
+            return Err(ProblemError::with_details(
+                "too-many-deliveries",
+                format!(
+                    "More deliveries to {size}-person teams than the {teams} available",
+                    teams = input.teams_of(size)
+                ),
+                serde_json::json!({
+                    "delivery": delivery,
+                    "team_size": size,
+                    "teams": input.teams_of(size),
+                }),
+            ));
+        }
+
+        let mut distinct = 0u64;
+        for &pizza in pizzas {
+            if pizza >= input.pizzas.len() {
+                return Err(ProblemError::with_details(
+                    "unknown-pizza",
+                    format!("Delivery {delivery} includes pizza {pizza} which does not exist"),
+                    serde_json::json!({ "delivery": delivery, "pizza": pizza }),
+                ));
+            }
+            if delivered[pizza] {
+                return Err(ProblemError::with_details(
+                    "duplicate-pizza",
+                    format!("Pizza {pizza} is delivered more than once"),
+                    serde_json::json!({ "delivery": delivery, "pizza": pizza }),
+                ));
+            }
+            delivered[pizza] = true;
+            for &ingredient in &input.pizzas[pizza] {
+                let slot = &mut counted[ingredient as usize];
+                if *slot != delivery {
+                    *slot = delivery;
+                    distinct += 1;
+                }
+            }
+        }
+        total_score += distinct * distinct;
+        pizzas_delivered += size;
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        deliveries,
+        pizzas_delivered,
+    })
+}
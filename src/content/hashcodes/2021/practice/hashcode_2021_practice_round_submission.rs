@@ -0,0 +1,76 @@
+use crate::hashcodes::error::ProblemError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Pizzas delivered to each team; the team size is the number of pizzas.
+    pub deliveries: Vec<Vec<usize>>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut lines = raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or_else(|| {
+        ProblemError::new(
+            "missing-delivery-count",
+            "Submission must start with the number of deliveries",
+        )
+    })?;
+    let declared_count = header.trim().parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-delivery-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": header.trim() }),
+        )
+    })?;
+
+    let mut deliveries = Vec::with_capacity(declared_count.min(1 << 20));
+    for (index, line) in lines {
+        let line_no = index + 1;
+        let numbers = line
+            .split_whitespace()
+            .map(|value| {
+                value.parse::<usize>().map_err(|_err| {
+                    ProblemError::with_details(
+                        "invalid-number",
+                        format!("Value '{value}' on line {line_no} is not a non-negative integer"),
+                        serde_json::json!({ "line": line_no, "value": value }),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let team_size = numbers[0];
+        if !(2..=4).contains(&team_size) {
+            return Err(ProblemError::with_details(
+                "invalid-team-size",
+                format!("Delivery on line {line_no} is for a team of {team_size}; teams have 2 to 4 people"),
+                serde_json::json!({ "line": line_no, "team_size": team_size }),
+            ));
+        }
+        if numbers.len() - 1 != team_size {
+            return Err(ProblemError::with_details(
+                "pizza-count-mismatch",
+                format!(
+                    "Delivery on line {line_no} is for {team_size} people but lists {actual} pizzas",
+                    actual = numbers.len() - 1
+                ),
+                serde_json::json!({ "line": line_no, "team_size": team_size, "pizzas": numbers.len() - 1 }),
+            ));
+        }
+        deliveries.push(numbers[1..].to_vec());
+    }
+
+    if deliveries.len() != declared_count {
+        return Err(ProblemError::with_details(
+            "delivery-count-mismatch",
+            format!(
+                "Submission declares {declared_count} deliveries but provided {actual}",
+                actual = deliveries.len()
+            ),
+            serde_json::json!({ "declared": declared_count, "actual": deliveries.len() }),
+        ));
+    }
+
+    Ok(ProblemSubmission { deliveries })
+}
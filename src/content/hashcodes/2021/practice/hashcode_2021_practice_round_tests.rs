@@ -0,0 +1,62 @@
+use super::input::load_input;
+use super::submission::parse_submission;
+use super::HashCode2021Practice;
+use crate::hashcodes::test_support::Fixture;
+
+fn example() -> Fixture<HashCode2021Practice> {
+    Fixture::dataset(HashCode2021Practice, "a_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2021Practice, "e_many_teams.in").input;
+    assert_eq!(parsed.pizzas.len(), 100_000);
+    assert!(parsed.teams.iter().sum::<usize>() > 0);
+}
+
+#[test]
+fn example_dataset_is_found_without_extension() {
+    assert!(load_input("a_example").is_ok());
+    assert!(load_input("example").is_ok());
+    let input = example().input;
+    assert_eq!(input.teams, [1, 2, 1]);
+    assert_eq!(input.ingredient_count, 7);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("2\n2 1 4\n3 0 2 3\n");
+    assert_eq!(breakdown.total_score, 65);
+    assert_eq!(breakdown.deliveries, [1, 1, 0]);
+    assert_eq!(breakdown.pizzas_delivered, 5);
+}
+
+#[test]
+fn submission_parser_checks_team_sizes() {
+    assert_eq!(
+        parse_submission("1\n2 0 1 2\n").expect_err("fail").code,
+        "pizza-count-mismatch"
+    );
+    assert_eq!(
+        parse_submission("1\n1 0\n").expect_err("fail").code,
+        "invalid-team-size"
+    );
+    assert_eq!(
+        parse_submission("2\n2 0 1\n").expect_err("fail").code,
+        "delivery-count-mismatch"
+    );
+}
+
+#[test]
+fn scoring_rejects_invalid_deliveries() {
+    let example = example();
+    assert_eq!(
+        example.score_error("2\n2 0 1\n2 2 3\n").code,
+        "too-many-deliveries"
+    );
+    assert_eq!(
+        example.score_error("2\n2 0 1\n3 1 2 3\n").code,
+        "duplicate-pizza"
+    );
+    assert_eq!(example.score_error("1\n2 0 5\n").code, "unknown-pizza");
+}
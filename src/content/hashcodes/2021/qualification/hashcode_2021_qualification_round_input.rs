@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Street {
+    pub name: String,
+    pub start: usize,
+    pub end: usize,
+    pub length: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub duration: u64,
+    pub intersections: usize,
+    pub bonus: u64,
+    pub streets: Vec<Street>,
+    /// Street indices every car drives through, starting at the end of the
+    /// first one.
+    pub cars: Vec<Vec<usize>>,
+    /// Maps a street name to its index in `streets`.
+    pub street_index: HashMap<String, usize>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_example",
+        file: "a_example.in",
+        aliases: &["example"],
+        contents: include_str!("hashcode_2021_qualification_round.in/a_example.in"),
+    },
+    Dataset {
+        name: "b_ocean",
+        file: "b_ocean.in",
+        aliases: &["ocean"],
+        contents: include_str!("hashcode_2021_qualification_round.in/b_ocean.in"),
+    },
+    Dataset {
+        name: "c_checkmate",
+        file: "c_checkmate.in",
+        aliases: &["checkmate"],
+        contents: include_str!("hashcode_2021_qualification_round.in/c_checkmate.in"),
+    },
+    Dataset {
+        name: "d_daily_commute",
+        file: "d_daily_commute.in",
+        aliases: &["daily_commute"],
+        contents: include_str!("hashcode_2021_qualification_round.in/d_daily_commute.in"),
+    },
+    Dataset {
+        name: "e_etoile",
+        file: "e_etoile.in",
+        aliases: &["etoile"],
+        contents: include_str!("hashcode_2021_qualification_round.in/e_etoile.in"),
+    },
+    Dataset {
+        name: "f_forever_jammed",
+        file: "f_forever_jammed.in",
+        aliases: &["forever_jammed"],
+        contents: include_str!("hashcode_2021_qualification_round.in/f_forever_jammed.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2021", "qualification", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let duration = tokens.parse::<u64>("duration")?;
+    let intersections = tokens.parse::<usize>("intersections")?;
+    let street_count = tokens.parse::<usize>("streets")?;
+    let car_count = tokens.parse::<usize>("cars")?;
+    let bonus = tokens.parse::<u64>("bonus")?;
+
+    let mut streets = Vec::with_capacity(street_count);
+    let mut street_index = HashMap::with_capacity(street_count);
+    for index in 0..street_count {
+        let start = tokens.parse::<usize>("street_start")?;
+        let end = tokens.parse::<usize>("street_end")?;
+        let name = tokens.token("street_name")?;
+        let length = tokens.parse::<u64>("street_length")?;
+        if start >= intersections || end >= intersections {
+            return Err(ProblemError::with_details(
+                "invalid-street",
+                format!("Street '{name}' connects an unknown intersection"),
+                serde_json::json!({ "street": name, "start": start, "end": end, "line": tokens.line() }),
+            ));
+        }
+        street_index.insert(name.to_string(), index);
+        streets.push(Street {
+            name: name.to_string(),
+            start,
+            end,
+            length,
+        });
+    }
+
+    let mut cars = Vec::with_capacity(car_count);
+    for _ in 0..car_count {
+        let path_length = tokens.parse::<usize>("path_length")?;
+        let mut path = Vec::with_capacity(path_length);
+        for _ in 0..path_length {
+            let name = tokens.token("path_street")?;
+            let street = street_index.get(name).copied().ok_or_else(|| {
+                ProblemError::with_details(
+                    "invalid-street",
+                    format!(
+                        "Car path on line {line} uses unknown street '{name}'",
+                        line = tokens.line()
+                    ),
+                    serde_json::json!({ "street": name, "line": tokens.line() }),
+                )
+            })?;
+            path.push(street);
+        }
+        cars.push(path);
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        duration,
+        intersections,
+        bonus,
+        streets,
+        cars,
+        street_index,
+    })
+}
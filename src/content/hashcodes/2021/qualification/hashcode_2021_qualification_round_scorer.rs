@@ -0,0 +1,219 @@
+use std::collections::VecDeque;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub cars_finished: usize,
+    /// Mean arrival second of the cars that finished in time.
+    pub average_finish_time: Option<f64>,
+    /// `(street name, longest queue)` for every street where a car waited.
+    pub longest_queues: Vec<(String, usize)>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let queues: serde_json::Map<String, serde_json::Value> = self
+            .longest_queues
+            .into_iter()
+            .map(|(street, queue)| (street, serde_json::json!(queue)))
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "cars_finished": self.cars_finished,
+            "average_finish_time": self.average_finish_time,
+            "longest_queues": queues,
+        })
+    }
+}
+
+/// Green window `[start, end)` of a street within its intersection's cycle.
+#[derive(Clone, Copy)]
+struct Light {
+    start: u64,
+    end: u64,
+    cycle: u64,
+}
+
+impl Light {
+    fn is_green(self, second: u64) -> bool {
+        let offset = second % self.cycle;
+        self.start <= offset && offset < self.end
+    }
+}
+
+/// Simulates the traffic lights second by second and computes the official
+/// score:
+/// - every scheduled intersection exists and is scheduled at most once
+/// - a schedule lists at least one street, each street at most once, and
+///   only streets that end at that intersection
+/// - every green light lasts at least one second
+///
+/// Cars start queued at the end of their first street. When its light is
+/// green, one car per second crosses from the front of a street's queue. A car
+/// reaching the end of its last street at second `t <= D` scores
+/// `F + (D - t)`.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let mut scheduled = vec![false; input.intersections];
+    let mut lights: Vec<Option<Light>> = vec![None; input.streets.len()];
+    for (index, schedule) in submission.schedules.iter().enumerate() {
+        let intersection = schedule.intersection;
+        let details = serde_json::json!({ "schedule": index, "intersection": intersection });
+        if intersection >= input.intersections {
+            return Err(ProblemError::with_details(
+                "unknown-intersection",
+                format!("Intersection {intersection} does not exist"),
+                details,
+            ));
+        }
+        if scheduled[intersection] {
+            return Err(ProblemError::with_details(
+                "duplicate-intersection",
+                format!("Intersection {intersection} is scheduled more than once"),
+                details,
+            ));
+        }
+        scheduled[intersection] = true;
+        if schedule.lights.is_empty() {
+            return Err(ProblemError::with_details(
+                "empty-schedule",
+                format!("Schedule for intersection {intersection} lists no street"),
+                details,
+            ));
+        }
+
+        // Bounding every light by D keeps the cycle sum from overflowing.
+        for (name, seconds) in &schedule.lights {
+            if *seconds == 0 || *seconds > input.duration {
+                return Err(ProblemError::with_details(
+                    "invalid-duration",
+                    format!(
+                        "Street '{name}' must stay green between 1 and {duration} seconds",
+                        duration = input.duration
+                    ),
+                    serde_json::json!({
+                        "schedule": index,
+                        "intersection": intersection,
+                        "street": name,
+                        "seconds": seconds,
+                    }),
+                ));
+            }
+        }
+        let cycle: u64 = schedule.lights.iter().map(|(_, seconds)| seconds).sum();
+        let mut start = 0;
+        for (name, seconds) in &schedule.lights {
+            let details = serde_json::json!({
+                "schedule": index,
+                "intersection": intersection,
+                "street": name,
+            });
+            let street = input.street_index.get(name).copied().ok_or_else(|| {
+                ProblemError::with_details(
+                    "unknown-street",
+                    format!("Street '{name}' does not exist"),
+                    details.clone(),
+                )
+            })?;
+            if input.streets[street].end != intersection {
+                return Err(ProblemError::with_details(
+                    "street-not-incoming",
+                    format!("Street '{name}' does not enter intersection {intersection}"),
+                    details,
+                ));
+            }
+            if lights[street].is_some() {
+                return Err(ProblemError::with_details(
+                    "duplicate-street",
+                    format!("Street '{name}' is listed twice at intersection {intersection}"),
+                    details,
+                ));
+            }
+            lights[street] = Some(Light {
+                start,
+                end: start + seconds,
+                cycle,
+            });
+            start += seconds;
+        }
+    }
+
+    let duration = input.duration;
+    let mut queues: Vec<VecDeque<usize>> = vec![VecDeque::new(); input.streets.len()];
+    let mut longest_queue = vec![0usize; input.streets.len()];
+    let mut busy_streets: Vec<usize> = Vec::new();
+    // Cars reaching the end of a street, bucketed by second, as (car, street).
+    let mut arrivals: Vec<Vec<(usize, usize)>> = vec![Vec::new(); duration as usize + 1];
+    // Position of every car in its path.
+    let mut position = vec![0usize; input.cars.len()];
+    for (car, path) in input.cars.iter().enumerate() {
+        arrivals[0].push((car, path[0]));
+    }
+
+    let mut total_score = 0;
+    let mut cars_finished = 0;
+    let mut finish_time_sum = 0u64;
+    for second in 0..duration {
+        for &(car, street) in &arrivals[second as usize] {
+            if queues[street].is_empty() {
+                busy_streets.push(street);
+            }
+            queues[street].push_back(car);
+        }
+
+        let mut still_busy = 0;
+        for index in 0..busy_streets.len() {
+            let street = busy_streets[index];
+            let queue = &mut queues[street];
+            longest_queue[street] = longest_queue[street].max(queue.len());
+            if lights[street].is_some_and(|light| light.is_green(second)) {
+                let car = queue.pop_front().expect("busy streets have queued cars");
+                position[car] += 1;
+                let next = input.cars[car][position[car]];
+                let arrival = second + input.streets[next].length;
+                if position[car] + 1 == input.cars[car].len() {
+                    if arrival <= duration {
+                        cars_finished += 1;
+                        finish_time_sum += arrival;
+                        total_score += input.bonus + (duration - arrival);
+                    }
+                } else if arrival < duration {
+                    arrivals[arrival as usize].push((car, next));
+                }
+            }
+            if !queues[street].is_empty() {
+                busy_streets[still_busy] = street;
+                still_busy += 1;
+            }
+        }
+        busy_streets.truncate(still_busy);
+    }
+
+    let longest_queues = longest_queue
+        .iter()
+        .enumerate()
+        .filter(|&(_, &queue)| queue > 0)
+        .map(|(street, &queue)| (input.streets[street].name.clone(), queue))
+        .collect();
+
+    Ok(ScoreBreakdown {
+        total_score,
+        cars_finished,
+        average_finish_time: (cars_finished > 0)
+            .then(|| finish_time_sum as f64 / cars_finished as f64),
+        longest_queues,
+    })
+}
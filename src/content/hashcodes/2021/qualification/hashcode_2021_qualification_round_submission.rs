@@ -0,0 +1,50 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+/// Green-light cycle of one intersection: `(street name, seconds)` pairs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Schedule {
+    pub intersection: usize,
+    pub lights: Vec<(String, u64)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub schedules: Vec<Schedule>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-schedule-count",
+            "Submission must start with the number of intersection schedules",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-schedule-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+
+    let mut schedules = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        let intersection = tokens.parse::<usize>("intersection")?;
+        let light_count = tokens.parse::<usize>("street_count")?;
+        let mut lights = Vec::with_capacity(light_count.min(1 << 16));
+        for _ in 0..light_count {
+            let street = tokens.token("street")?.to_string();
+            let seconds = tokens.parse::<u64>("green_duration")?;
+            lights.push((street, seconds));
+        }
+        schedules.push(Schedule {
+            intersection,
+            lights,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { schedules })
+}
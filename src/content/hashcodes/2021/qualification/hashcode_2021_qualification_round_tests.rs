@@ -0,0 +1,100 @@
+use super::HashCode2021Qualification;
+use crate::hashcodes::test_support::Fixture;
+
+const EXAMPLE_SUBMISSION: &str = "3
+1
+2
+rue-d-athenes 2
+rue-d-amsterdam 1
+0
+1
+rue-de-londres 2
+2
+1
+rue-de-moscou 1
+";
+
+fn example() -> Fixture<HashCode2021Qualification> {
+    Fixture::dataset(HashCode2021Qualification, "a_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2021Qualification, "d_daily_commute").input;
+    assert_eq!(parsed.cars.len(), 1000);
+    assert!(parsed.streets.len() > 1000);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 1002);
+    assert_eq!(breakdown.cars_finished, 1);
+    assert_eq!(breakdown.average_finish_time, Some(4.0));
+}
+
+#[test]
+fn queued_cars_cross_one_per_second() {
+    // Three cars wait at the end of the same street and each needs one more
+    // one-second street: they arrive at seconds 1, 2 and 3.
+    let queue = Fixture::inline(
+        HashCode2021Qualification,
+        "4 2 2 3 10\n0 1 in 1\n1 0 out 1\n2 in out\n2 in out\n2 in out\n",
+    );
+    let breakdown = queue.score("1\n1\n1\nin 1\n");
+    assert_eq!(breakdown.total_score, 3 * 10 + 3 + 2 + 1);
+    assert_eq!(breakdown.average_finish_time, Some(2.0));
+    assert_eq!(breakdown.longest_queues, vec![("in".to_string(), 3)]);
+}
+
+#[test]
+fn unscheduled_lights_stay_red() {
+    let breakdown = example().score("0\n");
+    assert_eq!(breakdown.total_score, 0);
+    assert_eq!(breakdown.average_finish_time, None);
+}
+
+#[test]
+fn scoring_rejects_invalid_schedules() {
+    let example = example();
+    assert_eq!(
+        example
+            .score_error("1\n1\n2\nrue-d-athenes 1\nrue-d-athenes 1\n")
+            .code,
+        "duplicate-street"
+    );
+    assert_eq!(
+        example.score_error("1\n1\n1\nrue-de-rome 1\n").code,
+        "street-not-incoming"
+    );
+    assert_eq!(
+        example.score_error("1\n1\n1\nrue-de-paris 1\n").code,
+        "unknown-street"
+    );
+    assert_eq!(
+        example
+            .score_error("2\n0\n1\nrue-de-londres 1\n0\n1\nrue-de-londres 1\n")
+            .code,
+        "duplicate-intersection"
+    );
+    assert_eq!(
+        example.score_error("1\n4\n1\nrue-de-londres 1\n").code,
+        "unknown-intersection"
+    );
+    assert_eq!(
+        example.score_error("1\n0\n1\nrue-de-londres 0\n").code,
+        "invalid-duration"
+    );
+    assert_eq!(example.score_error("1\n0\n0\n").code, "empty-schedule");
+}
+
+#[test]
+fn scoring_rejects_green_lights_longer_than_the_simulation() {
+    let example = example();
+    let huge = "1\n1\n2\nrue-d-athenes 9223372036854775808\nrue-d-amsterdam 9223372036854775808\n";
+    assert_eq!(example.score_error(huge).code, "invalid-duration");
+    assert_eq!(
+        example.score_error("1\n0\n1\nrue-de-londres 7\n").code,
+        "invalid-duration"
+    );
+}
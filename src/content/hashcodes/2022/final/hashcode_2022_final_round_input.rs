@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Gift {
+    pub child: String,
+    pub score: u64,
+    pub weight: u64,
+    pub column: i64,
+    pub row: i64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub time_limit: u64,
+    /// Maximum distance for loading at Lapland and for deliveries.
+    pub range: u64,
+    /// `(maximum weight, maximum acceleration)` ranges in increasing weight
+    /// order; heavier sleighs cannot accelerate.
+    pub accelerations: Vec<(u64, u64)>,
+    pub gifts: Vec<Gift>,
+    /// Maps a child's name to the index of their gift in `gifts`.
+    pub gift_index: HashMap<String, usize>,
+}
+
+impl ProblemInput {
+    /// Maximum acceleration of a sleigh weighing `weight` kilograms.
+    pub fn max_acceleration(&self, weight: u64) -> u64 {
+        self.accelerations
+            .iter()
+            .find(|&&(max_weight, _)| weight <= max_weight)
+            .map_or(0, |&(_, acceleration)| acceleration)
+    }
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_an_example",
+        file: "a_an_example.in.txt",
+        aliases: &["an_example", "example"],
+        contents: include_str!("hashcode_2022_final_round.in/a_an_example.in.txt"),
+    },
+    Dataset {
+        name: "b_better_hurry",
+        file: "b_better_hurry.in.txt",
+        aliases: &["better_hurry"],
+        contents: include_str!("hashcode_2022_final_round.in/b_better_hurry.in.txt"),
+    },
+    Dataset {
+        name: "c_carousel",
+        file: "c_carousel.in.txt",
+        aliases: &["carousel"],
+        contents: include_str!("hashcode_2022_final_round.in/c_carousel.in.txt"),
+    },
+    Dataset {
+        name: "d_decorated_houses",
+        file: "d_decorated_houses.in.txt",
+        aliases: &["decorated_houses"],
+        contents: include_str!("hashcode_2022_final_round.in/d_decorated_houses.in.txt"),
+    },
+    Dataset {
+        name: "e_excellent_weather",
+        file: "e_excellent_weather.in.txt",
+        aliases: &["excellent_weather"],
+        contents: include_str!("hashcode_2022_final_round.in/e_excellent_weather.in.txt"),
+    },
+    Dataset {
+        name: "f_festive_flyover",
+        file: "f_festive_flyover.in.txt",
+        aliases: &["festive_flyover"],
+        contents: include_str!("hashcode_2022_final_round.in/f_festive_flyover.in.txt"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2022", "final", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let time_limit = tokens.parse::<u64>("time_limit")?;
+    let range = tokens.parse::<u64>("range")?;
+    let acceleration_count = tokens.parse::<usize>("acceleration_ranges")?;
+    let gift_count = tokens.parse::<usize>("gifts")?;
+
+    let mut accelerations = Vec::with_capacity(acceleration_count);
+    for _ in 0..acceleration_count {
+        let max_weight = tokens.parse::<u64>("max_weight")?;
+        let acceleration = tokens.parse::<u64>("max_acceleration")?;
+        if accelerations
+            .last()
+            .is_some_and(|&(previous, _)| previous >= max_weight)
+        {
+            return Err(ProblemError::with_details(
+                "invalid-acceleration-range",
+                format!(
+                    "Acceleration ranges must have increasing weights (line {line})",
+                    line = tokens.line()
+                ),
+                serde_json::json!({ "max_weight": max_weight, "line": tokens.line() }),
+            ));
+        }
+        accelerations.push((max_weight, acceleration));
+    }
+
+    let mut gifts = Vec::with_capacity(gift_count);
+    let mut gift_index = HashMap::with_capacity(gift_count);
+    for index in 0..gift_count {
+        let child = tokens.token("child")?;
+        let score = tokens.parse::<u64>("gift_score")?;
+        let weight = tokens.parse::<u64>("gift_weight")?;
+        let column = tokens.parse::<i64>("gift_column")?;
+        let row = tokens.parse::<i64>("gift_row")?;
+        gift_index.insert(child.to_string(), index);
+        gifts.push(Gift {
+            child: child.to_string(),
+            score,
+            weight,
+            column,
+            row,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        time_limit,
+        range,
+        accelerations,
+        gifts,
+        gift_index,
+    })
+}
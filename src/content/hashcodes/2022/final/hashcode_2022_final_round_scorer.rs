@@ -0,0 +1,282 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::{Action, ProblemSubmission};
+
+/// Most carrots a single `LoadCarrots` action may load.
+const MAX_CARROTS_PER_LOAD: u64 = 1_000_000;
+
+/// Sleigh state at the start of the flight and at the end of every float.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Waypoint {
+    pub time: u64,
+    pub column: i64,
+    pub row: i64,
+    /// Velocity the sleigh flew with to reach this point.
+    pub velocity: (i64, i64),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delivery {
+    pub child: String,
+    pub time: u64,
+    pub score: u64,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub deliveries: Vec<Delivery>,
+    pub carrots_eaten: u64,
+    /// Gifts loaded but never delivered.
+    pub undelivered_gifts: usize,
+    pub trajectory: Vec<Waypoint>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let deliveries: Vec<serde_json::Value> = self
+            .deliveries
+            .iter()
+            .map(|delivery| {
+                serde_json::json!({
+                    "child": delivery.child,
+                    "time": delivery.time,
+                    "score": delivery.score,
+                })
+            })
+            .collect();
+        let trajectory: Vec<serde_json::Value> = self
+            .trajectory
+            .iter()
+            .map(|point| {
+                serde_json::json!({
+                    "time": point.time,
+                    "position": [point.column, point.row],
+                    "velocity": [point.velocity.0, point.velocity.1],
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "deliveries": deliveries,
+            "carrots_eaten": self.carrots_eaten,
+            "undelivered_gifts": self.undelivered_gifts,
+            "trajectory": trajectory,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum GiftState {
+    AtLapland,
+    Loaded,
+    Delivered,
+}
+
+/// True when `(column, row)` lies within Euclidean distance `range` of the
+/// target.
+fn within(range: u64, column: i64, row: i64, target: (i64, i64)) -> bool {
+    let dc = (column - target.0) as i128;
+    let dr = (row - target.1) as i128;
+    dc * dc + dr * dr <= (range as i128) * (range as i128)
+}
+
+/// Flies the sleigh through the actions and computes the official score:
+/// - carrots and gifts are loaded within the range of Lapland at `(0, 0)`,
+///   each gift at most once and 1 to 1,000,000 carrots at a time
+/// - a gift is delivered once, after being loaded, within range of its child
+/// - accelerating eats a carrot, needs one on board, stays within the maximum
+///   acceleration for the sleigh's weight (gifts, carrots and the carrot
+///   about to be eaten) and is separated from the previous one by a float
+/// - floats last at least one second and end by the time limit `T`
+///
+/// Each delivered gift scores its value.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let mut gifts = vec![GiftState::AtLapland; input.gifts.len()];
+    let (mut column, mut row) = (0i64, 0i64);
+    let mut velocity = (0i64, 0i64);
+    let mut time = 0u64;
+    let mut carrots = 0u64;
+    let mut gift_weight = 0u64;
+    let mut accelerated = false;
+
+    let mut total_score = 0;
+    let mut carrots_eaten = 0;
+    let mut deliveries = Vec::new();
+    let mut trajectory = vec![Waypoint {
+        time,
+        column,
+        row,
+        velocity,
+    }];
+
+    for (index, action) in submission.actions.iter().enumerate() {
+        let fail = |code: &'static str, message: String| {
+            ProblemError::with_details(
+                code,
+                format!("Action {index} at second {time}: {message}"),
+                serde_json::json!({
+                    "action": index,
+                    "time": time,
+                    "position": [column, row],
+                }),
+            )
+        };
+        let find_gift = |child: &str| {
+            input
+                .gift_index
+                .get(child)
+                .copied()
+                .ok_or_else(|| fail("unknown-gift", format!("there is no gift for '{child}'")))
+        };
+        match action {
+            Action::Accelerate { direction, amount } => {
+                if accelerated {
+                    return Err(fail(
+                        "double-acceleration",
+                        "the sleigh must float between two accelerations".to_string(),
+                    ));
+                }
+                if carrots == 0 {
+                    return Err(fail(
+                        "no-carrots",
+                        "there is no carrot left to accelerate".to_string(),
+                    ));
+                }
+                let weight = gift_weight + carrots;
+                let max = input.max_acceleration(weight);
+                if *amount > max {
+                    return Err(fail(
+                        "acceleration-too-high",
+                        format!("a sleigh of {weight} kg accelerates by at most {max}"),
+                    ));
+                }
+                let (dc, dr) = direction.unit();
+                velocity.0 += dc * *amount as i64;
+                velocity.1 += dr * *amount as i64;
+                carrots -= 1;
+                carrots_eaten += 1;
+                accelerated = true;
+            }
+            Action::Float { seconds } => {
+                if *seconds == 0 {
+                    return Err(fail(
+                        "invalid-float",
+                        "the sleigh must float for at least one second".to_string(),
+                    ));
+                }
+                if time.saturating_add(*seconds) > input.time_limit {
+                    return Err(fail(
+                        "time-limit-exceeded",
+                        format!(
+                            "floating {seconds} seconds goes past the time limit of {limit}",
+                            limit = input.time_limit
+                        ),
+                    ));
+                }
+                column += velocity.0 * *seconds as i64;
+                row += velocity.1 * *seconds as i64;
+                time += seconds;
+                accelerated = false;
+                trajectory.push(Waypoint {
+                    time,
+                    column,
+                    row,
+                    velocity,
+                });
+            }
+            Action::LoadCarrots { carrots: count } => {
+                if !(1..=MAX_CARROTS_PER_LOAD).contains(count) {
+                    return Err(fail(
+                        "invalid-carrot-count",
+                        format!("{count} carrots is outside 1..={MAX_CARROTS_PER_LOAD}"),
+                    ));
+                }
+                if !within(input.range, column, row, (0, 0)) {
+                    return Err(fail(
+                        "out-of-lapland-range",
+                        "carrots can only be loaded within range of Lapland".to_string(),
+                    ));
+                }
+                carrots += count;
+            }
+            Action::LoadGift { child } => {
+                let gift = find_gift(child)?;
+                if gifts[gift] != GiftState::AtLapland {
+                    return Err(fail(
+                        "gift-already-loaded",
+                        format!("the gift for '{child}' was already loaded"),
+                    ));
+                }
+                if !within(input.range, column, row, (0, 0)) {
+                    return Err(fail(
+                        "out-of-lapland-range",
+                        format!(
+                            "the gift for '{child}' can only be loaded within range of Lapland"
+                        ),
+                    ));
+                }
+                gifts[gift] = GiftState::Loaded;
+                gift_weight += input.gifts[gift].weight;
+            }
+            Action::DeliverGift { child } => {
+                let gift = find_gift(child)?;
+                match gifts[gift] {
+                    GiftState::AtLapland => {
+                        return Err(fail(
+                            "gift-not-loaded",
+                            format!("the gift for '{child}' is not on the sleigh"),
+                        ))
+                    }
+                    GiftState::Delivered => {
+                        return Err(fail(
+                            "gift-already-delivered",
+                            format!("the gift for '{child}' was already delivered"),
+                        ))
+                    }
+                    GiftState::Loaded => {}
+                }
+                let spec = &input.gifts[gift];
+                if !within(input.range, column, row, (spec.column, spec.row)) {
+                    return Err(fail(
+                        "child-out-of-range",
+                        format!(
+                            "'{child}' at ({c}, {r}) is out of delivery range",
+                            c = spec.column,
+                            r = spec.row
+                        ),
+                    ));
+                }
+                gifts[gift] = GiftState::Delivered;
+                gift_weight -= spec.weight;
+                total_score += spec.score;
+                deliveries.push(Delivery {
+                    child: child.clone(),
+                    time,
+                    score: spec.score,
+                });
+            }
+        }
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        deliveries,
+        carrots_eaten,
+        undelivered_gifts: gifts
+            .iter()
+            .filter(|&&state| state == GiftState::Loaded)
+            .count(),
+        trajectory,
+    })
+}
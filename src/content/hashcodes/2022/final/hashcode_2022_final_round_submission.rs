@@ -0,0 +1,119 @@
+use crate::hashcodes::error::ProblemError;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Unit `(column, row)` step of the direction.
+    pub fn unit(self) -> (i64, i64) {
+        match self {
+            Direction::Up => (0, 1),
+            Direction::Down => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Action {
+    Accelerate { direction: Direction, amount: u64 },
+    Float { seconds: u64 },
+    LoadCarrots { carrots: u64 },
+    LoadGift { child: String },
+    DeliverGift { child: String },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub actions: Vec<Action>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut lines = raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or_else(|| {
+        ProblemError::new(
+            "missing-action-count",
+            "Submission must start with the number of actions",
+        )
+    })?;
+    let declared_count = header.trim().parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-action-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": header.trim() }),
+        )
+    })?;
+
+    let mut actions = Vec::with_capacity(declared_count.min(1 << 20));
+    for (index, line) in lines {
+        actions.push(parse_action(line, index + 1)?);
+    }
+
+    if actions.len() != declared_count {
+        return Err(ProblemError::with_details(
+            "action-count-mismatch",
+            format!(
+                "Submission declares {declared_count} actions but provided {actual}",
+                actual = actions.len()
+            ),
+            serde_json::json!({ "declared": declared_count, "actual": actions.len() }),
+        ));
+    }
+
+    Ok(ProblemSubmission { actions })
+}
+
+fn parse_action(line: &str, line_no: usize) -> Result<Action, ProblemError> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let number = |value: &str| -> Result<u64, ProblemError> {
+        value.parse::<u64>().map_err(|_err| {
+            ProblemError::with_details(
+                "invalid-number",
+                format!("Value '{value}' on line {line_no} is not a non-negative integer"),
+                serde_json::json!({ "line": line_no, "value": value }),
+            )
+        })
+    };
+    let accelerate = |direction: Direction, value: &str| -> Result<Action, ProblemError> {
+        Ok(Action::Accelerate {
+            direction,
+            amount: number(value)?,
+        })
+    };
+
+    match parts.as_slice() {
+        ["AccUp", value] => accelerate(Direction::Up, value),
+        ["AccDown", value] => accelerate(Direction::Down, value),
+        ["AccLeft", value] => accelerate(Direction::Left, value),
+        ["AccRight", value] => accelerate(Direction::Right, value),
+        ["Float", value] => Ok(Action::Float {
+            seconds: number(value)?,
+        }),
+        ["LoadCarrots", value] => Ok(Action::LoadCarrots {
+            carrots: number(value)?,
+        }),
+        ["LoadGift", child] => Ok(Action::LoadGift {
+            child: child.to_string(),
+        }),
+        ["DeliverGift", child] => Ok(Action::DeliverGift {
+            child: child.to_string(),
+        }),
+        _ => Err(ProblemError::with_details(
+            "invalid-action",
+            format!(
+                "Line {line_no} is not a valid Acc*, Float, LoadCarrots, LoadGift or \
+                 DeliverGift action"
+            ),
+            serde_json::json!({ "line": line_no, "content": line }),
+        )),
+    }
+}
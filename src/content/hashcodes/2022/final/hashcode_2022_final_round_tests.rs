@@ -0,0 +1,134 @@
+use super::submission::parse_submission;
+use super::HashCode2022Final;
+use crate::hashcodes::test_support::Fixture;
+
+const EXAMPLE_SUBMISSION: &str = "23
+LoadCarrots 10
+LoadGift Olivia
+LoadGift Liam
+AccRight 4
+Float 1
+DeliverGift Olivia
+AccUp 2
+Float 1
+DeliverGift Liam
+AccLeft 8
+Float 1
+AccDown 4
+Float 1
+LoadGift Bob
+AccRight 4
+Float 1
+AccDown 6
+Float 1
+AccDown 6
+Float 1
+AccDown 6
+Float 4
+DeliverGift Bob
+";
+
+fn example() -> Fixture<HashCode2022Final> {
+    Fixture::dataset(HashCode2022Final, "a_an_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2022Final, "c_carousel").input;
+    assert_eq!(parsed.time_limit, 5000);
+    assert_eq!(parsed.accelerations.len(), 3);
+    assert_eq!(parsed.gifts.len(), 10000);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 16);
+    assert_eq!(breakdown.carrots_eaten, 8);
+    assert_eq!(breakdown.undelivered_gifts, 0);
+
+    let last = breakdown.trajectory.last().expect("trajectory");
+    assert_eq!((last.time, last.column, last.row), (11, 0, -102));
+    assert_eq!(last.velocity, (0, -20));
+    let positions: Vec<(i64, i64)> = breakdown.trajectory[..4]
+        .iter()
+        .map(|point| (point.column, point.row))
+        .collect();
+    assert_eq!(positions, vec![(0, 0), (4, 0), (8, 2), (4, 4)]);
+}
+
+#[test]
+fn heavy_sleighs_accelerate_less() {
+    let example = example();
+    let input = &example.input;
+    assert_eq!(input.max_acceleration(15), 8);
+    assert_eq!(input.max_acceleration(16), 6);
+    assert_eq!(input.max_acceleration(61), 0);
+
+    // Ten carrots and Olivia's gift weigh 20 kg: at most 6.
+    let error = example.score_error("4\nLoadCarrots 10\nLoadGift Olivia\nAccUp 7\nFloat 1\n");
+    assert_eq!(error.code, "acceleration-too-high");
+    assert_eq!(error.details.expect("details")["action"], 2);
+}
+
+#[test]
+fn scoring_rejects_invalid_flights() {
+    let example = example();
+    assert_eq!(
+        example
+            .score_error("3\nLoadCarrots 5\nAccUp 1\nAccUp 1\n")
+            .code,
+        "double-acceleration"
+    );
+    assert_eq!(example.score_error("1\nAccUp 1\n").code, "no-carrots");
+    assert_eq!(
+        example
+            .score_error("4\nLoadCarrots 5\nAccUp 4\nFloat 1\nLoadCarrots 1\n")
+            .code,
+        "out-of-lapland-range"
+    );
+    assert_eq!(
+        example
+            .score_error("2\nLoadGift Bob\nDeliverGift Bob\n")
+            .code,
+        "child-out-of-range"
+    );
+    assert_eq!(
+        example.score_error("1\nDeliverGift Emma\n").code,
+        "gift-not-loaded"
+    );
+    assert_eq!(
+        example
+            .score_error("2\nLoadGift Emma\nLoadGift Emma\n")
+            .code,
+        "gift-already-loaded"
+    );
+    assert_eq!(
+        example.score_error("1\nLoadGift Santa\n").code,
+        "unknown-gift"
+    );
+    assert_eq!(
+        example.score_error("1\nFloat 16\n").code,
+        "time-limit-exceeded"
+    );
+    assert_eq!(
+        example.score_error("1\nLoadCarrots 0\n").code,
+        "invalid-carrot-count"
+    );
+}
+
+#[test]
+fn submission_parser_reports_malformed_actions() {
+    assert_eq!(
+        parse_submission("1\nAccSideways 1\n")
+            .expect_err("should fail")
+            .code,
+        "invalid-action"
+    );
+    assert_eq!(
+        parse_submission("2\nFloat 1\n")
+            .expect_err("should fail")
+            .code,
+        "action-count-mismatch"
+    );
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+/// Interned ingredient ids a client likes and dislikes, sorted and without
+/// repetitions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Client {
+    pub likes: Vec<u32>,
+    pub dislikes: Vec<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub clients: Vec<Client>,
+    /// Ingredient names indexed by interned id.
+    pub ingredients: Vec<String>,
+    /// Maps an ingredient name to its interned id.
+    pub ingredient_index: HashMap<String, u32>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_an_example",
+        file: "a_an_example.in.txt",
+        aliases: &["an_example", "example"],
+        contents: include_str!("hashcode_2022_practice_round.in/a_an_example.in.txt"),
+    },
+    Dataset {
+        name: "b_basic",
+        file: "b_basic.in.txt",
+        aliases: &["basic"],
+        contents: include_str!("hashcode_2022_practice_round.in/b_basic.in.txt"),
+    },
+    Dataset {
+        name: "c_coarse",
+        file: "c_coarse.in.txt",
+        aliases: &["coarse"],
+        contents: include_str!("hashcode_2022_practice_round.in/c_coarse.in.txt"),
+    },
+    Dataset {
+        name: "d_difficult",
+        file: "d_difficult.in.txt",
+        aliases: &["difficult"],
+        contents: include_str!("hashcode_2022_practice_round.in/d_difficult.in.txt"),
+    },
+    Dataset {
+        name: "e_elaborate",
+        file: "e_elaborate.in.txt",
+        aliases: &["elaborate"],
+        contents: include_str!("hashcode_2022_practice_round.in/e_elaborate.in.txt"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2022", "practice", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let client_count = tokens.parse::<usize>("clients")?;
+
+    let mut ingredients: Vec<String> = Vec::new();
+    let mut ingredient_index: HashMap<String, u32> = HashMap::new();
+    let mut clients = Vec::with_capacity(client_count);
+    for _ in 0..client_count {
+        let mut preferences = [Vec::new(), Vec::new()];
+        for (list, field) in preferences.iter_mut().zip(["liked", "disliked"]) {
+            let count = tokens.parse::<usize>(field)?;
+            for _ in 0..count {
+                let name = tokens.token("ingredient")?;
+                let id = match ingredient_index.get(name) {
+                    Some(&id) => id,
+                    None => {
+                        let id = ingredients.len() as u32;
+                        ingredients.push(name.to_string());
+                        ingredient_index.insert(name.to_string(), id);
+                        id
+                    }
+                };
+                list.push(id);
+            }
+            list.sort_unstable();
+            list.dedup();
+        }
+        let [likes, dislikes] = preferences;
+        clients.push(Client { likes, dislikes });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        clients,
+        ingredients,
+        ingredient_index,
+    })
+}
@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::ProblemInput;
+use super::submission::ProblemSubmission;
+
+/// Unhappy clients are only listed one by one up to this many.
+const UNHAPPY_CLIENT_LIMIT: usize = 20;
+
+/// Why a client stays away from the pizzeria.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnhappyClient {
+    pub client: usize,
+    /// Liked ingredients missing from the pizza.
+    pub missing: Vec<String>,
+    /// Disliked ingredients present on the pizza.
+    pub unwanted: Vec<String>,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    pub unhappy_client_count: usize,
+    /// Every unhappy client, or `None` when there are more than
+    /// [`UNHAPPY_CLIENT_LIMIT`].
+    pub unhappy_clients: Option<Vec<UnhappyClient>>,
+    /// Submitted ingredients no client mentions; they do not affect the score.
+    pub unknown_ingredients: Vec<String>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let unhappy = self.unhappy_clients.map(|clients| {
+            clients
+                .into_iter()
+                .map(|client| {
+                    serde_json::json!({
+                        "client": client.client,
+                        "missing": client.missing,
+                        "unwanted": client.unwanted,
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+        let warnings: Vec<serde_json::Value> = self
+            .unknown_ingredients
+            .iter()
+            .map(|ingredient| {
+                serde_json::json!({
+                    "code": "unknown-ingredient",
+                    "message": format!("No client mentions ingredient '{ingredient}'"),
+                    "ingredient": ingredient,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "unhappy_client_count": self.unhappy_client_count,
+            "unhappy_clients": unhappy,
+            "warnings": warnings,
+        })
+    }
+}
+
+/// Validates the pizza and computes the official score:
+/// - every ingredient is listed at most once
+///
+/// Ingredients no client mentions are reported as warnings. A client counts
+/// for one point when the pizza holds every ingredient they like and none they
+/// dislike.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let mut on_pizza = vec![false; input.ingredients.len()];
+    let mut seen: HashSet<&str> = HashSet::with_capacity(submission.ingredients.len());
+    let mut unknown_ingredients = Vec::new();
+    for (position, name) in submission.ingredients.iter().enumerate() {
+        if !seen.insert(name) {
+            return Err(ProblemError::with_details(
+                "duplicate-ingredient",
+                format!("Ingredient '{name}' is listed more than once"),
+                serde_json::json!({ "ingredient": name, "position": position }),
+            ));
+        }
+        match input.ingredient_index.get(name) {
+            Some(&id) => on_pizza[id as usize] = true,
+            None => unknown_ingredients.push(name.clone()),
+        }
+    }
+
+    let names = |ids: Vec<u32>| -> Vec<String> {
+        ids.into_iter()
+            .map(|id| input.ingredients[id as usize].clone())
+            .collect()
+    };
+    let mut total_score = 0;
+    let mut unhappy_client_count = 0;
+    let mut unhappy_clients = Some(Vec::new());
+    for (client, preferences) in input.clients.iter().enumerate() {
+        let happy = preferences.likes.iter().all(|&id| on_pizza[id as usize])
+            && !preferences.dislikes.iter().any(|&id| on_pizza[id as usize]);
+        if happy {
+            total_score += 1;
+            continue;
+        }
+        unhappy_client_count += 1;
+        if unhappy_client_count > UNHAPPY_CLIENT_LIMIT {
+            unhappy_clients = None;
+        }
+        if let Some(unhappy) = unhappy_clients.as_mut() {
+            let missing = preferences
+                .likes
+                .iter()
+                .copied()
+                .filter(|&id| !on_pizza[id as usize])
+                .collect();
+            let unwanted = preferences
+                .dislikes
+                .iter()
+                .copied()
+                .filter(|&id| on_pizza[id as usize])
+                .collect();
+            unhappy.push(UnhappyClient {
+                client,
+                missing: names(missing),
+                unwanted: names(unwanted),
+            });
+        }
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        unhappy_client_count,
+        unhappy_clients,
+        unknown_ingredients,
+    })
+}
@@ -0,0 +1,33 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    /// Ingredient names put on the pizza, in submission order.
+    pub ingredients: Vec<String>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let count = tokens.next_token().ok_or_else(|| {
+        ProblemError::new(
+            "missing-ingredient-count",
+            "Submission must start with the number of ingredients",
+        )
+    })?;
+    let count = count.parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-ingredient-count",
+            "First value must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": count }),
+        )
+    })?;
+
+    let mut ingredients = Vec::with_capacity(count.min(1 << 20));
+    for _ in 0..count {
+        ingredients.push(tokens.token("ingredient")?.to_string());
+    }
+    tokens.finish()?;
+
+    Ok(ProblemSubmission { ingredients })
+}
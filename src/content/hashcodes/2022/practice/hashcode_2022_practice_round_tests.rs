@@ -0,0 +1,66 @@
+use super::submission::parse_submission;
+use super::HashCode2022Practice;
+use crate::hashcodes::test_support::Fixture;
+
+fn example() -> Fixture<HashCode2022Practice> {
+    Fixture::dataset(HashCode2022Practice, "a_an_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2022Practice, "d_difficult").input;
+    assert_eq!(parsed.clients.len(), 9368);
+    assert!(!parsed.ingredients.is_empty());
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score("4 cheese mushrooms tomatoes peppers\n");
+    assert_eq!(breakdown.total_score, 2);
+    let unhappy = breakdown.unhappy_clients.expect("short list");
+    assert_eq!(unhappy.len(), 1);
+    assert_eq!(unhappy[0].client, 1);
+    assert_eq!(unhappy[0].missing, vec!["basil".to_string()]);
+    assert!(unhappy[0].unwanted.is_empty());
+}
+
+#[test]
+fn disliked_ingredients_turn_clients_away() {
+    let breakdown = example().score("3 basil mushrooms tomatoes\n");
+    assert_eq!(breakdown.total_score, 1);
+    let unhappy = breakdown.unhappy_clients.expect("short list");
+    assert_eq!(unhappy[1].client, 2);
+    assert_eq!(unhappy[1].unwanted, vec!["basil".to_string()]);
+}
+
+#[test]
+fn unknown_ingredients_are_warnings() {
+    let breakdown = example().score("3 cheese peppers anchovies\n");
+    assert_eq!(breakdown.total_score, 1);
+    assert_eq!(breakdown.unknown_ingredients, vec!["anchovies".to_string()]);
+}
+
+#[test]
+fn long_unhappy_lists_are_summarised() {
+    let breakdown = Fixture::dataset(HashCode2022Practice, "d_difficult").score("0\n");
+    assert!(breakdown.unhappy_client_count > 20);
+    assert!(breakdown.unhappy_clients.is_none());
+}
+
+#[test]
+fn duplicate_ingredients_are_rejected() {
+    assert_eq!(
+        example().score_error("2 cheese cheese\n").code,
+        "duplicate-ingredient"
+    );
+    assert_eq!(
+        parse_submission("3 cheese\n")
+            .expect_err("should fail")
+            .code,
+        "missing-value"
+    );
+    assert_eq!(
+        parse_submission("x\n").expect_err("should fail").code,
+        "invalid-ingredient-count"
+    );
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::parse::Tokens;
+use crate::hashcodes::problem::{find_dataset, Dataset};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Contributor {
+    pub name: String,
+    /// `(interned skill, level)` pairs sorted by skill.
+    pub skills: Vec<(u32, u32)>,
+}
+
+impl Contributor {
+    /// Level of `skill`, or zero when the contributor does not have it.
+    pub fn level(&self, skill: u32) -> u32 {
+        level_in(&self.skills, skill)
+    }
+}
+
+/// Looks `skill` up in a list of `(skill, level)` pairs sorted by skill.
+pub fn level_in(skills: &[(u32, u32)], skill: u32) -> u32 {
+    skills
+        .binary_search_by_key(&skill, |&(id, _)| id)
+        .map_or(0, |position| skills[position].1)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Project {
+    pub name: String,
+    pub duration: u64,
+    pub score: u64,
+    pub best_before: u64,
+    /// `(interned skill, required level)` for every role, in input order.
+    pub roles: Vec<(u32, u32)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemInput {
+    pub contributors: Vec<Contributor>,
+    pub projects: Vec<Project>,
+    /// Skill names indexed by interned id.
+    pub skills: Vec<String>,
+    /// Maps a contributor name to its index in `contributors`.
+    pub contributor_index: HashMap<String, usize>,
+    /// Maps a project name to its index in `projects`.
+    pub project_index: HashMap<String, usize>,
+}
+
+pub static DATASETS: &[Dataset] = &[
+    Dataset {
+        name: "a_an_example",
+        file: "a_an_example.in",
+        aliases: &["an_example", "example"],
+        contents: include_str!("hashcode_2022_qualification_round.in/a_an_example.in"),
+    },
+    Dataset {
+        name: "b_better_start_small",
+        file: "b_better_start_small.in",
+        aliases: &["better_start_small"],
+        contents: include_str!("hashcode_2022_qualification_round.in/b_better_start_small.in"),
+    },
+    Dataset {
+        name: "c_collaboration",
+        file: "c_collaboration.in",
+        aliases: &["collaboration"],
+        contents: include_str!("hashcode_2022_qualification_round.in/c_collaboration.in"),
+    },
+    Dataset {
+        name: "d_dense_schedule",
+        file: "d_dense_schedule.in",
+        aliases: &["dense_schedule"],
+        contents: include_str!("hashcode_2022_qualification_round.in/d_dense_schedule.in"),
+    },
+    Dataset {
+        name: "e_exceptional_skills",
+        file: "e_exceptional_skills.in",
+        aliases: &["exceptional_skills"],
+        contents: include_str!("hashcode_2022_qualification_round.in/e_exceptional_skills.in"),
+    },
+    Dataset {
+        name: "f_find_great_mentors",
+        file: "f_find_great_mentors.in",
+        aliases: &["find_great_mentors"],
+        contents: include_str!("hashcode_2022_qualification_round.in/f_find_great_mentors.in"),
+    },
+];
+
+pub fn load_input(input_file: &str) -> Result<&'static str, ProblemError> {
+    find_dataset("2022", "qualification", DATASETS, input_file).map(|dataset| dataset.contents)
+}
+
+pub fn parse_input(raw: &str) -> Result<ProblemInput, ProblemError> {
+    let mut tokens = Tokens::new(raw);
+    let contributor_count = tokens.parse::<usize>("contributors")?;
+    let project_count = tokens.parse::<usize>("projects")?;
+
+    let mut skills: Vec<String> = Vec::new();
+    let mut skill_index: HashMap<&str, u32> = HashMap::new();
+    let mut contributors = Vec::with_capacity(contributor_count);
+    let mut contributor_index = HashMap::with_capacity(contributor_count);
+    for index in 0..contributor_count {
+        let name = tokens.token("contributor_name")?;
+        let skill_count = tokens.parse::<usize>("skill_count")?;
+        let mut known = Vec::with_capacity(skill_count);
+        for _ in 0..skill_count {
+            let skill = tokens.token("skill_name")?;
+            let level = tokens.parse::<u32>("skill_level")?;
+            known.push((intern(skill, &mut skills, &mut skill_index), level));
+        }
+        known.sort_unstable();
+        known.dedup_by_key(|&mut (skill, _)| skill);
+        contributor_index.insert(name.to_string(), index);
+        contributors.push(Contributor {
+            name: name.to_string(),
+            skills: known,
+        });
+    }
+
+    let mut projects = Vec::with_capacity(project_count);
+    let mut project_index = HashMap::with_capacity(project_count);
+    for index in 0..project_count {
+        let name = tokens.token("project_name")?;
+        let duration = tokens.parse::<u64>("project_duration")?;
+        let score = tokens.parse::<u64>("project_score")?;
+        let best_before = tokens.parse::<u64>("best_before")?;
+        let role_count = tokens.parse::<usize>("role_count")?;
+        let mut roles = Vec::with_capacity(role_count);
+        for _ in 0..role_count {
+            let skill = tokens.token("role_skill")?;
+            let level = tokens.parse::<u32>("role_level")?;
+            roles.push((intern(skill, &mut skills, &mut skill_index), level));
+        }
+        project_index.insert(name.to_string(), index);
+        projects.push(Project {
+            name: name.to_string(),
+            duration,
+            score,
+            best_before,
+            roles,
+        });
+    }
+    tokens.finish()?;
+
+    Ok(ProblemInput {
+        contributors,
+        projects,
+        skills,
+        contributor_index,
+        project_index,
+    })
+}
+
+/// Returns the id of skill `name`, assigning the next one on first sight.
+fn intern<'a>(name: &'a str, skills: &mut Vec<String>, index: &mut HashMap<&'a str, u32>) -> u32 {
+    *index.entry(name).or_insert_with(|| {
+        skills.push(name.to_string());
+        skills.len() as u32 - 1
+    })
+}
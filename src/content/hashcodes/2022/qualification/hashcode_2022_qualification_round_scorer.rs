@@ -0,0 +1,245 @@
+use crate::hashcodes::error::ProblemError;
+use crate::hashcodes::problem::Breakdown;
+
+use super::input::{level_in, ProblemInput};
+use super::submission::ProblemSubmission;
+
+/// A skill a contributor improved by completing a project.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SkillUpgrade {
+    pub contributor: String,
+    pub skill: String,
+    /// Level reached after the project.
+    pub level: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectResult {
+    pub project: String,
+    pub start_day: u64,
+    /// First day on which the contributors are free again.
+    pub end_day: u64,
+    pub score: u64,
+    pub upgrades: Vec<SkillUpgrade>,
+}
+
+/// Captures the totals reported back through the API.
+#[derive(Clone, Debug)]
+pub struct ScoreBreakdown {
+    pub total_score: u64,
+    /// Projects finished after their best-before day.
+    pub late_projects: usize,
+    /// Every executed project, in submission order.
+    pub projects: Vec<ProjectResult>,
+}
+
+impl Breakdown for ScoreBreakdown {
+    fn total_score(&self) -> i64 {
+        self.total_score as i64
+    }
+
+    fn into_json(self) -> serde_json::Value {
+        let projects: Vec<serde_json::Value> = self
+            .projects
+            .into_iter()
+            .map(|project| {
+                let upgrades: Vec<serde_json::Value> = project
+                    .upgrades
+                    .into_iter()
+                    .map(|upgrade| {
+                        serde_json::json!({
+                            "contributor": upgrade.contributor,
+                            "skill": upgrade.skill,
+                            "level": upgrade.level,
+                        })
+                    })
+                    .collect();
+                serde_json::json!({
+                    "project": project.project,
+                    "start_day": project.start_day,
+                    "end_day": project.end_day,
+                    "score": project.score,
+                    "upgrades": upgrades,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "total_score": self.total_score,
+            "late_projects": self.late_projects,
+            "projects": projects,
+        })
+    }
+}
+
+/// Schedules the projects in submission order and computes the official
+/// score:
+/// - every project exists, is executed at most once and fills each of its
+///   roles with a distinct, known contributor
+/// - a contributor has the role's skill at the required level, or one level
+///   short when another contributor on the project has it at that level
+///
+/// A project starts on the first day all its contributors are free. Once it
+/// ends, every contributor whose level was at most the required one gains a
+/// level. A project ending on day `E` scores `S - max(0, E - B)`, never below
+/// zero.
+pub fn score(
+    input: &ProblemInput,
+    submission: &ProblemSubmission,
+) -> Result<ScoreBreakdown, ProblemError> {
+    let mut levels: Vec<Vec<(u32, u32)>> = input
+        .contributors
+        .iter()
+        .map(|contributor| contributor.skills.clone())
+        .collect();
+    let mut free_on = vec![0u64; input.contributors.len()];
+    let mut executed = vec![false; input.projects.len()];
+    // Last assignment each contributor was seen in, to catch repeats.
+    let mut seen_in = vec![usize::MAX; input.contributors.len()];
+
+    let mut total_score = 0;
+    let mut late_projects = 0;
+    let mut projects = Vec::with_capacity(submission.assignments.len());
+    for (index, assignment) in submission.assignments.iter().enumerate() {
+        let name = &assignment.project;
+        let project = input.project_index.get(name).copied().ok_or_else(|| {
+            ProblemError::with_details(
+                "unknown-project",
+                format!("Project '{name}' does not exist"),
+                serde_json::json!({ "assignment": index, "project": name }),
+            )
+        })?;
+        if executed[project] {
+            return Err(ProblemError::with_details(
+                "duplicate-project",
+                format!("Project '{name}' is executed more than once"),
+                serde_json::json!({ "assignment": index, "project": name }),
+            ));
+        }
+        executed[project] = true;
+        let spec = &input.projects[project];
+        if assignment.contributors.len() != spec.roles.len() {
+            return Err(ProblemError::with_details(
+                "role-count-mismatch",
+                format!(
+                    "Project '{name}' has {roles} roles but {assigned} contributors are assigned",
+                    roles = spec.roles.len(),
+                    assigned = assignment.contributors.len()
+                ),
+                serde_json::json!({
+                    "assignment": index,
+                    "project": name,
+                    "roles": spec.roles.len(),
+                    "assigned": assignment.contributors.len(),
+                }),
+            ));
+        }
+
+        let mut team = Vec::with_capacity(spec.roles.len());
+        for (role, contributor) in assignment.contributors.iter().enumerate() {
+            let details = serde_json::json!({
+                "assignment": index,
+                "project": name,
+                "role": role,
+                "contributor": contributor,
+            });
+            let member = input
+                .contributor_index
+                .get(contributor)
+                .copied()
+                .ok_or_else(|| {
+                    ProblemError::with_details(
+                        "unknown-contributor",
+                        format!("Contributor '{contributor}' does not exist"),
+                        details.clone(),
+                    )
+                })?;
+            if seen_in[member] == index {
+                return Err(ProblemError::with_details(
+                    "duplicate-contributor",
+                    format!("Contributor '{contributor}' fills two roles on project '{name}'"),
+                    details,
+                ));
+            }
+            seen_in[member] = index;
+            team.push(member);
+        }
+
+        for (role, &(skill, required)) in spec.roles.iter().enumerate() {
+            let level = level_in(&levels[team[role]], skill);
+            let mentored = level + 1 == required
+                && team.iter().enumerate().any(|(other, &mentor)| {
+                    other != role && level_in(&levels[mentor], skill) >= required
+                });
+            if level < required && !mentored {
+                let contributor = &assignment.contributors[role];
+                let skill_name = &input.skills[skill as usize];
+                return Err(ProblemError::with_details(
+                    "insufficient-skill",
+                    format!(
+                        "Contributor '{contributor}' has {skill_name} level {level} but role \
+                         {role} of project '{name}' requires level {required}"
+                    ),
+                    serde_json::json!({
+                        "assignment": index,
+                        "project": name,
+                        "role": role,
+                        "contributor": contributor,
+                        "skill": skill_name,
+                        "level": level,
+                        "required": required,
+                    }),
+                ));
+            }
+        }
+
+        let start_day = team
+            .iter()
+            .map(|&member| free_on[member])
+            .max()
+            .unwrap_or(0);
+        let end_day = start_day + spec.duration;
+        let delay = end_day.saturating_sub(spec.best_before);
+        let score = spec.score.saturating_sub(delay);
+        if delay > 0 {
+            late_projects += 1;
+        }
+        total_score += score;
+
+        let mut upgrades = Vec::new();
+        for (role, &(skill, required)) in spec.roles.iter().enumerate() {
+            let member = team[role];
+            free_on[member] = end_day;
+            let skills = &mut levels[member];
+            let level = match skills.binary_search_by_key(&skill, |&(id, _)| id) {
+                Ok(position) if skills[position].1 <= required => {
+                    skills[position].1 += 1;
+                    skills[position].1
+                }
+                Ok(_) => continue,
+                Err(position) => {
+                    skills.insert(position, (skill, 1));
+                    1
+                }
+            };
+            upgrades.push(SkillUpgrade {
+                contributor: input.contributors[member].name.clone(),
+                skill: input.skills[skill as usize].clone(),
+                level,
+            });
+        }
+
+        projects.push(ProjectResult {
+            project: name.clone(),
+            start_day,
+            end_day,
+            score,
+            upgrades,
+        });
+    }
+
+    Ok(ScoreBreakdown {
+        total_score,
+        late_projects,
+        projects,
+    })
+}
@@ -0,0 +1,65 @@
+use crate::hashcodes::error::ProblemError;
+
+/// Contributors assigned to a project's roles, in role order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assignment {
+    pub project: String,
+    pub contributors: Vec<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProblemSubmission {
+    pub assignments: Vec<Assignment>,
+}
+
+pub fn parse_submission(raw: &str) -> Result<ProblemSubmission, ProblemError> {
+    let mut lines = raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or_else(|| {
+        ProblemError::new(
+            "missing-project-count",
+            "Submission must start with the number of executed projects",
+        )
+    })?;
+    let declared_count = header.trim().parse::<usize>().map_err(|_err| {
+        ProblemError::with_details(
+            "invalid-project-count",
+            "First line must be a non-negative integer",
+            serde_json::json!({ "line": 1, "value": header.trim() }),
+        )
+    })?;
+
+    let mismatch = |actual: usize| {
+        ProblemError::with_details(
+            "project-count-mismatch",
+            format!("Submission declares {declared_count} projects but provided {actual}"),
+            serde_json::json!({ "declared": declared_count, "actual": actual }),
+        )
+    };
+    let mut assignments = Vec::with_capacity(declared_count.min(1 << 20));
+    for actual in 0..declared_count {
+        let (_, project) = lines.next().ok_or_else(|| mismatch(actual))?;
+        let (_, contributors) = lines.next().ok_or_else(|| mismatch(actual))?;
+        assignments.push(Assignment {
+            project: project.trim().to_string(),
+            contributors: contributors
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
+        });
+    }
+    if let Some((index, line)) = lines.next() {
+        return Err(ProblemError::with_details(
+            "trailing-data",
+            format!(
+                "Unexpected extra line {line_no} after the last project",
+                line_no = index + 1
+            ),
+            serde_json::json!({ "line": index + 1, "content": line }),
+        ));
+    }
+
+    Ok(ProblemSubmission { assignments })
+}
@@ -0,0 +1,119 @@
+use super::submission::parse_submission;
+use super::HashCode2022Qualification;
+use crate::hashcodes::test_support::Fixture;
+
+const EXAMPLE_SUBMISSION: &str = "3
+WebServer
+Bob Anna
+Logging
+Anna
+WebChat
+Maria Bob
+";
+
+/// Ann knows Python at level 2 and Ben at level 3; project P needs Python 3
+/// and Python 1.
+const MENTORING_INPUT: &str = "2 1
+Ann 1
+Python 2
+Ben 1
+Python 3
+P 3 10 2 2
+Python 3
+Python 1
+";
+
+fn example() -> Fixture<HashCode2022Qualification> {
+    Fixture::dataset(HashCode2022Qualification, "a_an_example")
+}
+
+#[test]
+fn official_datasets_parse() {
+    let parsed = Fixture::dataset(HashCode2022Qualification, "f_find_great_mentors").input;
+    assert_eq!(parsed.contributors.len(), 1000);
+    assert_eq!(parsed.projects.len(), 19413);
+}
+
+#[test]
+fn scoring_matches_statement_example() {
+    let breakdown = example().score(EXAMPLE_SUBMISSION);
+    assert_eq!(breakdown.total_score, 33);
+    assert_eq!(breakdown.late_projects, 1);
+
+    let starts: Vec<(u64, u64)> = breakdown
+        .projects
+        .iter()
+        .map(|project| (project.start_day, project.score))
+        .collect();
+    assert_eq!(starts, vec![(0, 10), (7, 3), (7, 20)]);
+    let anna = &breakdown.projects[1].upgrades;
+    assert_eq!(anna.len(), 1);
+    assert_eq!((anna[0].skill.as_str(), anna[0].level), ("C++", 4));
+    assert_eq!(breakdown.projects[2].upgrades[0].contributor, "Maria");
+}
+
+#[test]
+fn mentors_let_contributors_work_one_level_up() {
+    let mentoring = Fixture::inline(HashCode2022Qualification, MENTORING_INPUT);
+    let breakdown = mentoring.score("1\nP\nAnn Ben\n");
+    // The project ends on day 3, one day after its best-before day.
+    assert_eq!(breakdown.total_score, 9);
+    let upgrades = &breakdown.projects[0].upgrades;
+    assert_eq!(upgrades.len(), 1);
+    assert_eq!(
+        (upgrades[0].contributor.as_str(), upgrades[0].level),
+        ("Ann", 3)
+    );
+    assert_eq!(mentoring.input.contributors[0].level(0), 2);
+
+    // Without a mentor Ann is one level short.
+    let alone = MENTORING_INPUT.replacen("Python 3\nP", "Python 1\nP", 1);
+    let error = Fixture::inline(HashCode2022Qualification, &alone).score_error("1\nP\nAnn Ben\n");
+    assert_eq!(error.code, "insufficient-skill");
+    assert_eq!(error.details.expect("details")["role"], 0);
+}
+
+#[test]
+fn contributors_wait_for_their_previous_project() {
+    let breakdown = example().score("2\nWebChat\nMaria Bob\nWebServer\nBob Anna\n");
+    assert_eq!(breakdown.projects[1].start_day, 10);
+    // WebServer ends on day 17, ten days after its best-before day.
+    assert_eq!(breakdown.total_score, 20);
+}
+
+#[test]
+fn scoring_rejects_invalid_assignments() {
+    let example = example();
+    assert_eq!(
+        example.score_error("1\nLogging\nBob\n").code,
+        "insufficient-skill"
+    );
+    assert_eq!(
+        example.score_error("1\nDeploy\nAnna\n").code,
+        "unknown-project"
+    );
+    assert_eq!(
+        example
+            .score_error("2\nWebChat\nMaria Bob\nWebChat\nMaria Bob\n")
+            .code,
+        "duplicate-project"
+    );
+    assert_eq!(
+        example.score_error("1\nLogging\nAnna Bob\n").code,
+        "role-count-mismatch"
+    );
+    assert_eq!(
+        example.score_error("1\nLogging\nZoe\n").code,
+        "unknown-contributor"
+    );
+    assert_eq!(
+        example.score_error("1\nWebServer\nBob Bob\n").code,
+        "duplicate-contributor"
+    );
+    assert_eq!(
+        parse_submission("2\nLogging\nAnna\n")
+            .expect_err("should fail")
+            .code,
+        "project-count-mismatch"
+    );
+}
@@ -1,28 +1,110 @@
 //! Core helpers and routing glue for Google Hash Code scoring modules.
 
 pub mod error;
+pub mod parse;
+pub mod problem;
 pub mod response;
-
-#[path = "../content/hashcodes/2017/practice/hashcode_2017_practice_round.rs"]
-mod hashcode_2017_practice_round;
+#[cfg(test)]
+pub(crate) mod test_support;
+#[cfg(test)]
+mod tests;
 
 use error::ProblemError;
-use response::ScoreResponse;
+use problem::{find_dataset, Dataset, DynProblem};
+use response::{DatasetScore, RoundScoreResponse, ScoreResponse};
+
+/// Declares every round module and adds its [`problem::Problem`]
+/// implementation to [`REGISTRY`]. A new round only needs one entry here.
+macro_rules! register_problems {
+    ($($module:ident :: $problem:ident => $path:literal,)*) => {
+        $(
+            #[path = $path]
+            pub mod $module;
+        )*
 
-/// Identifier for the supported Hash Code problem modules.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum ProblemKey {
-    HashCode2017Practice,
+        /// Every implemented round, in chronological order.
+        pub static REGISTRY: &[&dyn DynProblem] = &[$(&$module::$problem),*];
+    };
 }
 
+register_problems! {
+    hashcode_2014_final_round::HashCode2014Final =>
+        "../content/hashcodes/2014/final/hashcode_2014_final_round.rs",
+    hashcode_2015_qualification_round::HashCode2015Qualification =>
+        "../content/hashcodes/2015/qualification/hashcode_2015_qualification_round.rs",
+    hashcode_2015_final_round::HashCode2015Final =>
+        "../content/hashcodes/2015/final/hashcode_2015_final_round.rs",
+    hashcode_2016_qualification_round::HashCode2016Qualification =>
+        "../content/hashcodes/2016/qualification/hashcode_2016_qualification_round.rs",
+    hashcode_2016_final_round::HashCode2016Final =>
+        "../content/hashcodes/2016/final/hashcode_2016_final_round.rs",
+    hashcode_2017_practice_round::HashCode2017Practice =>
+        "../content/hashcodes/2017/practice/hashcode_2017_practice_round.rs",
+    hashcode_2017_qualification_round::HashCode2017Qualification =>
+        "../content/hashcodes/2017/qualification/hashcode_2017_qualification_round.rs",
+    hashcode_2017_final_round::HashCode2017Final =>
+        "../content/hashcodes/2017/final/hashcode_2017_final_round.rs",
+    hashcode_2018_qualification_round::HashCode2018Qualification =>
+        "../content/hashcodes/2018/qualification/hashcode_2018_qualification_round.rs",
+    hashcode_2018_final_round::HashCode2018Final =>
+        "../content/hashcodes/2018/final/hashcode_2018_final_round.rs",
+    hashcode_2019_qualification_round::HashCode2019Qualification =>
+        "../content/hashcodes/2019/qualification/hashcode_2019_qualification_round.rs",
+    hashcode_2019_final_round::HashCode2019Final =>
+        "../content/hashcodes/2019/final/hashcode_2019_final_round.rs",
+    hashcode_2020_practice_round::HashCode2020Practice =>
+        "../content/hashcodes/2020/practice/hashcode_2020_practice_round.rs",
+    hashcode_2020_qualification_round::HashCode2020Qualification =>
+        "../content/hashcodes/2020/qualification/hashcode_2020_qualification_round.rs",
+    hashcode_2020_final_round::HashCode2020Final =>
+        "../content/hashcodes/2020/final/hashcode_2020_final_round.rs",
+    hashcode_2021_practice_round::HashCode2021Practice =>
+        "../content/hashcodes/2021/practice/hashcode_2021_practice_round.rs",
+    hashcode_2021_qualification_round::HashCode2021Qualification =>
+        "../content/hashcodes/2021/qualification/hashcode_2021_qualification_round.rs",
+    hashcode_2021_final_round::HashCode2021Final =>
+        "../content/hashcodes/2021/final/hashcode_2021_final_round.rs",
+    hashcode_2022_practice_round::HashCode2022Practice =>
+        "../content/hashcodes/2022/practice/hashcode_2022_practice_round.rs",
+    hashcode_2022_qualification_round::HashCode2022Qualification =>
+        "../content/hashcodes/2022/qualification/hashcode_2022_qualification_round.rs",
+    hashcode_2022_final_round::HashCode2022Final =>
+        "../content/hashcodes/2022/final/hashcode_2022_final_round.rs",
+}
+
+/// Handle to one of the registered Hash Code problem modules.
+#[derive(Clone, Copy)]
+pub struct ProblemKey(&'static dyn DynProblem);
+
 impl ProblemKey {
     pub fn from_route(year: &str, round: &str) -> Option<Self> {
-        match (year, round) {
-            ("2017", "practice") | ("2017", "practice_round") | ("2017", "practice_problem") => {
-                Some(ProblemKey::HashCode2017Practice)
-            }
-            _ => None,
-        }
+        Self::all().find(|key| {
+            key.year() == year && (key.round() == round || key.aliases().contains(&round))
+        })
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        REGISTRY.iter().map(|problem| ProblemKey(*problem))
+    }
+
+    pub fn id(self) -> &'static str {
+        self.0.id()
+    }
+
+    pub fn year(self) -> &'static str {
+        self.0.year()
+    }
+
+    pub fn round(self) -> &'static str {
+        self.0.round()
+    }
+
+    pub fn aliases(self) -> &'static [&'static str] {
+        self.0.aliases()
+    }
+
+    pub fn datasets(self) -> &'static [Dataset] {
+        self.0.datasets()
     }
 
     pub fn score_submission(
@@ -30,10 +112,120 @@ impl ProblemKey {
         input_file: &str,
         submission_text: &str,
     ) -> Result<ScoreResponse, ProblemError> {
-        match self {
-            ProblemKey::HashCode2017Practice => {
-                hashcode_2017_practice_round::score_submission(input_file, submission_text)
+        self.0.score_submission(input_file, submission_text)
+    }
+
+    /// Scores one submission per dataset, in parallel, keyed by any name
+    /// [`Dataset::matches`] accepts. Datasets without a submission and
+    /// rejected submissions count as zero; the latter keep their error.
+    /// Submissions matching no dataset are listed after the round's datasets
+    /// with an `unknown-input-file` error.
+    pub fn score_round(
+        self,
+        submissions: &[(&str, &str)],
+    ) -> Result<RoundScoreResponse, ProblemError> {
+        let datasets = self.datasets();
+        let mut assigned: Vec<Option<&str>> = vec![None; datasets.len()];
+        let mut unmatched = Vec::new();
+        for (name, text) in submissions {
+            let dataset = match find_dataset(self.year(), self.round(), datasets, name) {
+                Ok(dataset) => dataset,
+                Err(error) => {
+                    unmatched.push(DatasetScore {
+                        dataset: name.trim().to_owned(),
+                        score: 0,
+                        submitted: true,
+                        result: None,
+                        error: Some(error),
+                    });
+                    continue;
+                }
+            };
+            let index = datasets
+                .iter()
+                .position(|candidate| candidate.name == dataset.name)
+                .unwrap_or_default();
+            if assigned[index].replace(text).is_some() {
+                return Err(ProblemError::with_details(
+                    "duplicate-submission",
+                    format!("More than one submission for dataset '{}'", dataset.name),
+                    serde_json::json!({ "dataset": dataset.name }),
+                ));
             }
         }
+
+        let results: Vec<Option<Result<ScoreResponse, ProblemError>>> =
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = datasets
+                    .iter()
+                    .zip(&assigned)
+                    .map(|(dataset, text)| {
+                        text.map(|text| {
+                            scope.spawn(move || self.score_submission(dataset.name, text))
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle.map(|handle| {
+                            handle
+                                .join()
+                                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                        })
+                    })
+                    .collect()
+            });
+
+        let datasets: Vec<DatasetScore> = datasets
+            .iter()
+            .zip(results)
+            .map(|(dataset, result)| {
+                let (result, error) = match result {
+                    Some(Ok(score)) => (Some(score), None),
+                    Some(Err(error)) => (None, Some(error)),
+                    None => (None, None),
+                };
+                DatasetScore {
+                    dataset: dataset.name.to_owned(),
+                    score: result.as_ref().map_or(0, |score| score.score),
+                    submitted: result.is_some() || error.is_some(),
+                    result,
+                    error,
+                }
+            })
+            .chain(unmatched)
+            .collect();
+        Ok(RoundScoreResponse {
+            problem: self.id(),
+            total_score: datasets.iter().map(|dataset| dataset.score).sum(),
+            datasets,
+        })
+    }
+
+    pub fn validate_submission(self, submission_text: &str) -> Result<(), ProblemError> {
+        self.0.validate_submission(submission_text)
+    }
+
+    pub fn has_solver(self) -> bool {
+        self.0.has_solver()
+    }
+
+    pub fn solve(self, input_file: &str) -> Result<String, ProblemError> {
+        self.0.solve(input_file)
+    }
+}
+
+impl std::fmt::Debug for ProblemKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProblemKey").field(&self.id()).finish()
     }
 }
+
+impl PartialEq for ProblemKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for ProblemKey {}
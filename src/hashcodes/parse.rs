@@ -0,0 +1,87 @@
+//! Whitespace tokenizer shared by the input and submission parsers.
+
+use std::str::FromStr;
+
+use super::error::ProblemError;
+
+/// Reads whitespace separated tokens while keeping track of the current line
+/// so that errors can point at the offending part of the file.
+pub struct Tokens<'a> {
+    raw: &'a str,
+    position: usize,
+    line: usize,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(raw: &'a str) -> Self {
+        Self {
+            raw,
+            position: 0,
+            line: 1,
+        }
+    }
+
+    /// 1-based line number of the most recently read token.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn next_token(&mut self) -> Option<&'a str> {
+        let bytes = self.raw.as_bytes();
+        while self.position < bytes.len() && bytes[self.position].is_ascii_whitespace() {
+            if bytes[self.position] == b'\n' {
+                self.line += 1;
+            }
+            self.position += 1;
+        }
+        if self.position == bytes.len() {
+            return None;
+        }
+        let start = self.position;
+        while self.position < bytes.len() && !bytes[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+        Some(&self.raw[start..self.position])
+    }
+
+    /// Returns the next token or a `missing-value` error naming `field`.
+    pub fn token(&mut self, field: &str) -> Result<&'a str, ProblemError> {
+        self.next_token().ok_or_else(|| {
+            ProblemError::with_details(
+                "missing-value",
+                format!("Expected a value for '{field}' but the file ended"),
+                serde_json::json!({ "field": field, "line": self.line }),
+            )
+        })
+    }
+
+    /// Parses the next token, reporting `invalid-number` when it does not fit `T`.
+    pub fn parse<T: FromStr>(&mut self, field: &str) -> Result<T, ProblemError> {
+        let raw = self.token(field)?;
+        raw.parse::<T>().map_err(|_err| {
+            ProblemError::with_details(
+                "invalid-number",
+                format!(
+                    "Could not parse '{field}' on line {line} as a number",
+                    line = self.line
+                ),
+                serde_json::json!({ "field": field, "value": raw, "line": self.line }),
+            )
+        })
+    }
+
+    /// Fails with `trailing-data` if anything but whitespace is left.
+    pub fn finish(mut self) -> Result<(), ProblemError> {
+        match self.next_token() {
+            None => Ok(()),
+            Some(extra) => Err(ProblemError::with_details(
+                "trailing-data",
+                format!(
+                    "Unexpected extra value '{extra}' on line {line}",
+                    line = self.line
+                ),
+                serde_json::json!({ "value": extra, "line": self.line }),
+            )),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Shared contract implemented by every Hash Code round.
+//!
+//! A round implements [`Problem`] with its own strongly typed input,
+//! submission and breakdown. The registry in [`crate::hashcodes`] only sees
+//! the type-erased [`DynProblem`] view, which is derived automatically.
+
+use super::error::ProblemError;
+use super::response::ScoreResponse;
+
+/// One official input file bundled with a round.
+#[derive(Debug)]
+pub struct Dataset {
+    /// Canonical dataset name, e.g. `a_example`.
+    pub name: &'static str,
+    /// File name as shipped with the statement, e.g. `example.in`.
+    pub file: &'static str,
+    /// Additional names accepted when looking the dataset up.
+    pub aliases: &'static [&'static str],
+    pub contents: &'static str,
+}
+
+impl Dataset {
+    /// Returns true when `requested` names this dataset by its canonical
+    /// name, its file name or one of its aliases. Input and output file
+    /// extensions (`.in`, `.out`, `.txt`) are ignored.
+    pub fn matches(&self, requested: &str) -> bool {
+        let requested = strip_extensions(requested.trim());
+        requested == self.name
+            || requested == strip_extensions(self.file)
+            || self.aliases.contains(&requested)
+    }
+}
+
+fn strip_extensions(mut value: &str) -> &str {
+    loop {
+        let stripped = value
+            .strip_suffix(".txt")
+            .or_else(|| value.strip_suffix(".in"))
+            .or_else(|| value.strip_suffix(".out"));
+        match stripped {
+            Some(rest) => value = rest,
+            None => return value,
+        }
+    }
+}
+
+/// Looks up `input_file` in a round's dataset table.
+pub fn find_dataset<'a>(
+    year: &str,
+    round: &str,
+    datasets: &'a [Dataset],
+    input_file: &str,
+) -> Result<&'a Dataset, ProblemError> {
+    datasets
+        .iter()
+        .find(|dataset| dataset.matches(input_file))
+        .ok_or_else(|| {
+            let available: Vec<&str> = datasets.iter().map(|dataset| dataset.name).collect();
+            ProblemError::with_details(
+                "unknown-input-file",
+                format!(
+                    "Unsupported {year} {round} dataset '{requested}'",
+                    requested = strip_extensions(input_file.trim())
+                ),
+                serde_json::json!({
+                    "requested": input_file,
+                    "available": available,
+                }),
+            )
+        })
+}
+
+/// Result of scoring a submission, reported back through the API.
+pub trait Breakdown {
+    fn total_score(&self) -> i64;
+    fn into_json(self) -> serde_json::Value;
+}
+
+/// A single Hash Code round.
+pub trait Problem: Sync {
+    type Input;
+    type Submission;
+    type Breakdown: Breakdown;
+
+    /// Stable identifier reported as `problem` in score responses.
+    fn id(&self) -> &'static str;
+    fn year(&self) -> &'static str;
+    fn round(&self) -> &'static str;
+    /// Alternative spellings of [`Problem::round`] accepted in routes.
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn datasets(&self) -> &'static [Dataset];
+
+    fn parse_input(&self, raw: &str) -> Result<Self::Input, ProblemError>;
+    fn parse_submission(&self, raw: &str) -> Result<Self::Submission, ProblemError>;
+    fn score(
+        &self,
+        input: &Self::Input,
+        submission: &Self::Submission,
+    ) -> Result<Self::Breakdown, ProblemError>;
+
+    /// Reference solver bundled with the round, returning the submission in
+    /// the statement's output format. Most rounds ship without one.
+    fn solver(&self) -> Option<fn(&Self::Input) -> String> {
+        None
+    }
+
+    fn dataset(&self, input_file: &str) -> Result<&'static Dataset, ProblemError> {
+        find_dataset(self.year(), self.round(), self.datasets(), input_file)
+    }
+}
+
+/// Object-safe view of [`Problem`] used by the registry.
+pub trait DynProblem: Sync {
+    fn id(&self) -> &'static str;
+    fn year(&self) -> &'static str;
+    fn round(&self) -> &'static str;
+    fn aliases(&self) -> &'static [&'static str];
+    fn datasets(&self) -> &'static [Dataset];
+    fn score_submission(
+        &self,
+        input_file: &str,
+        submission_text: &str,
+    ) -> Result<ScoreResponse, ProblemError>;
+    /// Parses a submission without scoring it against any dataset.
+    fn validate_submission(&self, submission_text: &str) -> Result<(), ProblemError>;
+    fn has_solver(&self) -> bool;
+    /// Runs [`Problem::solver`] on one dataset.
+    fn solve(&self, input_file: &str) -> Result<String, ProblemError>;
+}
+
+impl<P: Problem> DynProblem for P {
+    fn id(&self) -> &'static str {
+        Problem::id(self)
+    }
+
+    fn year(&self) -> &'static str {
+        Problem::year(self)
+    }
+
+    fn round(&self) -> &'static str {
+        Problem::round(self)
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        Problem::aliases(self)
+    }
+
+    fn datasets(&self) -> &'static [Dataset] {
+        Problem::datasets(self)
+    }
+
+    fn score_submission(
+        &self,
+        input_file: &str,
+        submission_text: &str,
+    ) -> Result<ScoreResponse, ProblemError> {
+        let dataset = self.dataset(input_file)?;
+        let input = self.parse_input(dataset.contents)?;
+        let submission = self.parse_submission(submission_text)?;
+        let breakdown = self.score(&input, &submission)?;
+        Ok(ScoreResponse::with_details(
+            Problem::id(self),
+            input_file,
+            breakdown.total_score(),
+            breakdown.into_json(),
+        ))
+    }
+    fn validate_submission(&self, submission_text: &str) -> Result<(), ProblemError> {
+        self.parse_submission(submission_text).map(drop)
+    }
+
+    fn has_solver(&self) -> bool {
+        self.solver().is_some()
+    }
+
+    fn solve(&self, input_file: &str) -> Result<String, ProblemError> {
+        let solver = self.solver().ok_or_else(|| {
+            ProblemError::new(
+                "no-solver",
+                format!(
+                    "No solver is bundled with {year} {round}",
+                    year = Problem::year(self),
+                    round = Problem::round(self)
+                ),
+            )
+        })?;
+        let dataset = self.dataset(input_file)?;
+        let input = self.parse_input(dataset.contents)?;
+        Ok(solver(&input))
+    }
+}
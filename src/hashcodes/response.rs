@@ -1,5 +1,7 @@
 use serde::Serialize;
 
+use super::error::ProblemError;
+
 /// Successful score calculation payload returned by the API.
 #[derive(Debug, Serialize)]
 pub struct ScoreResponse {
@@ -34,3 +36,26 @@ impl ScoreResponse {
         }
     }
 }
+
+/// Outcome for one dataset of a round scored with
+/// [`crate::hashcodes::ProblemKey::score_round`].
+#[derive(Debug, Serialize)]
+pub struct DatasetScore {
+    /// Canonical dataset name, or the submitted name when it matches none.
+    pub dataset: String,
+    /// Zero when the dataset has no submission or the submission was rejected.
+    pub score: i64,
+    pub submitted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<ScoreResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ProblemError>,
+}
+
+/// Scores for every dataset of a round. Hash Code ranks teams by the total.
+#[derive(Debug, Serialize)]
+pub struct RoundScoreResponse {
+    pub problem: &'static str,
+    pub total_score: i64,
+    pub datasets: Vec<DatasetScore>,
+}
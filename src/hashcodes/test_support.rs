@@ -0,0 +1,52 @@
+//! Fixtures shared by the round test modules.
+
+use super::error::ProblemError;
+use super::problem::Problem;
+
+/// A round together with one parsed input, for scoring submissions in tests.
+pub struct Fixture<P: Problem> {
+    problem: P,
+    pub input: P::Input,
+}
+
+impl<P: Problem> Fixture<P> {
+    /// Parses one of the round's bundled datasets.
+    pub fn dataset(problem: P, name: &str) -> Self {
+        let dataset = problem
+            .dataset(name)
+            .unwrap_or_else(|error| panic!("dataset should exist: {error}"));
+        Self::inline(problem, dataset.contents)
+    }
+
+    /// Parses an input written out in the test, usually the statement example.
+    pub fn inline(problem: P, raw: &str) -> Self {
+        let input = problem
+            .parse_input(raw)
+            .unwrap_or_else(|error| panic!("input should parse: {error}"));
+        Self { problem, input }
+    }
+
+    /// Parses and scores `submission`, which must be accepted.
+    pub fn score(&self, submission: &str) -> P::Breakdown {
+        let submission = self.submission(submission);
+        self.problem
+            .score(&self.input, &submission)
+            .unwrap_or_else(|error| panic!("submission should score: {error}"))
+    }
+
+    /// Parses `submission`, which must be well-formed, and returns why
+    /// scoring rejects it.
+    pub fn score_error(&self, submission: &str) -> ProblemError {
+        let submission = self.submission(submission);
+        match self.problem.score(&self.input, &submission) {
+            Ok(_) => panic!("submission should be rejected"),
+            Err(error) => error,
+        }
+    }
+
+    fn submission(&self, raw: &str) -> P::Submission {
+        self.problem
+            .parse_submission(raw)
+            .unwrap_or_else(|error| panic!("submission should parse: {error}"))
+    }
+}
@@ -0,0 +1,24 @@
+//! Registry checks that span several rounds.
+
+use super::ProblemKey;
+
+#[test]
+fn only_rounds_with_a_bundled_solver_can_solve() {
+    let solvable: Vec<&str> = ProblemKey::all()
+        .filter(|key| key.has_solver())
+        .map(|key| key.id())
+        .collect();
+    assert_eq!(
+        solvable,
+        [
+            "hashcode_2017_practice_round",
+            "hashcode_2020_practice_round"
+        ]
+    );
+
+    let other = ProblemKey::from_route("2017", "qualification").expect("registered");
+    assert_eq!(
+        other.solve("me_at_the_zoo").expect_err("no solver").code,
+        "no-solver"
+    );
+}